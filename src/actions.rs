@@ -1,6 +1,7 @@
 use crate::{
     coordinates::{PieceCoord, TileCoord},
     piece::PieceType,
+    player::Player,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -13,11 +14,16 @@ pub enum Command {
         piece_type: PieceType,
         coord: TileCoord,
     },
+    /// Declares a battle, which is resolved once the defending player responds with
+    /// [`Command::Defend`]
     Battle {
         target: PieceCoord,
-        target_is_defending: bool,
         initiator: BattleActor,
         attack_supporters: Vec<BattleActor>,
+    },
+    /// Responds to the pending battle as the defending player
+    Defend {
+        target_is_defending: bool,
         defence_supporters: Vec<BattleActor>,
     },
     EndTurn,
@@ -28,3 +34,11 @@ pub enum BattleActor {
     Static { coord: PieceCoord },
     Moving { from: PieceCoord, to: TileCoord },
 }
+
+/// A command accepted by a [`Game`](crate::Game) along with when and by whom it was issued
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoggedCommand {
+    pub turn: u32,
+    pub player: Player,
+    pub command: Command,
+}
@@ -0,0 +1,488 @@
+use crate::{
+    Game,
+    actions::{BattleActor, Command},
+    coordinates::{PieceCoord, TileCoord},
+    error::{BattleRole, CommandError},
+    piece::Piece,
+    player::Player,
+};
+use std::{cmp::Reverse, collections::HashMap};
+
+/// Every piece that could take part in a battle against a target, as found by
+/// [`Game::battle_options`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BattleOptions {
+    pub target: PieceCoord,
+    /// Whether the target is able to defend itself
+    pub target_can_defend: bool,
+    pub initiators: Vec<ActorOption>,
+    pub attack_supporters: Vec<ActorOption>,
+    pub defence_supporters: Vec<ActorOption>,
+}
+
+/// The ways a single piece can take part in a battle
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActorOption {
+    pub coord: PieceCoord,
+    /// Whether the piece can take part from where it stands
+    pub in_place: bool,
+    /// The tiles the piece can move to and take part from
+    pub destinations: Vec<TileCoord>,
+}
+
+impl ActorOption {
+    /// Returns every battle actor the piece can be used as
+    pub fn actors(&self) -> impl Iterator<Item = BattleActor> + '_ {
+        self.in_place
+            .then_some(BattleActor::Static { coord: self.coord })
+            .into_iter()
+            .chain(self.destinations.iter().map(|to| BattleActor::Moving {
+                from: self.coord,
+                to: *to,
+            }))
+    }
+}
+
+/// A battle declared by the current player and awaiting the response of the defending player
+///
+/// The attacking pieces have already moved and been exhausted, so their coords are those
+/// after moving.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingBattle {
+    pub target: PieceCoord,
+    pub initiator: BattleActor,
+    pub attack_supporters: Vec<BattleActor>,
+}
+
+impl PendingBattle {
+    /// Returns the coords of the attacking pieces after they moved into position
+    pub fn attacker_coords(&self) -> impl Iterator<Item = PieceCoord> + '_ {
+        std::iter::once(&self.initiator)
+            .chain(&self.attack_supporters)
+            .map(|actor| match actor {
+                BattleActor::Static { coord } => *coord,
+                BattleActor::Moving { to, .. } => PieceCoord::new_unchecked(to.as_coord()),
+            })
+    }
+}
+
+/// A breakdown of how a battle was resolved
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BattleReport {
+    pub target: PieceCoord,
+    /// Every piece that took part, attackers first, followed by the target if it defended and
+    /// then the defence supporters
+    pub participants: Vec<Participant>,
+    pub attack_power: u8,
+    pub defence_power: u8,
+    pub outcome: BattleOutcome,
+}
+
+/// A piece that took part in a battle and what it contributed
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Participant {
+    pub piece: Piece,
+    /// Where the piece fought from, after any movement
+    pub coord: PieceCoord,
+    pub side: Side,
+    /// The power of the piece itself
+    pub power: u8,
+    /// The defence bonus of the tile the piece fought from, if it applied
+    pub terrain_bonus: u8,
+}
+
+impl Participant {
+    #[must_use]
+    pub const fn total(self) -> u8 {
+        self.power.saturating_add(self.terrain_bonus)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Side {
+    Attack,
+    Defence,
+}
+
+/// The result of a battle
+///
+/// The attack must be strictly more powerful than the defence to win, so ties are draws. Only
+/// the attacking side winning has an effect, destroying the target; otherwise every piece
+/// survives.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BattleOutcome {
+    AttackerWins,
+    DefenderWins,
+    Draw,
+}
+
+impl BattleOutcome {
+    #[must_use]
+    pub const fn from_powers(attack_power: u8, defence_power: u8) -> Self {
+        if attack_power > defence_power {
+            Self::AttackerWins
+        } else if attack_power < defence_power {
+            Self::DefenderWins
+        } else {
+            Self::Draw
+        }
+    }
+}
+
+/// The predicted result of a battle, as found by [`Game::preview_battle`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BattlePreview {
+    /// The battle resolved with the previewed response, or with [`Self::best_response`] when
+    /// previewing a declaration
+    pub report: BattleReport,
+    /// The coords of the pieces that would be destroyed
+    pub destroyed: Vec<PieceCoord>,
+    /// The pieces that would move during the battle, from either side
+    pub moves: Vec<(PieceCoord, TileCoord)>,
+    /// The [`Command::Defend`] giving the defending side the most power
+    pub best_response: Command,
+    /// The battle resolved with [`Self::best_response`]
+    pub best_report: BattleReport,
+}
+
+impl Game {
+    /// Predicts the result of a [`Command::Battle`] declaration, or of a [`Command::Defend`]
+    /// response to the pending battle, without changing the game
+    ///
+    /// A declaration is previewed against the best response of the defending player.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `command` is not a legal battle declaration or response.
+    pub fn preview_battle(&self, command: &Command) -> Result<BattlePreview, CommandError> {
+        let mut game = self.snapshot();
+        let response = match command {
+            Command::Battle {
+                target,
+                initiator,
+                attack_supporters,
+            } => {
+                game.check_battle(*target, *initiator, attack_supporters)?;
+                game.apply_battle(*target, *initiator, attack_supporters);
+                None
+            }
+            Command::Defend {
+                target_is_defending,
+                defence_supporters,
+            } => {
+                game.check_defend(*target_is_defending, defence_supporters)?;
+                Some((*target_is_defending, defence_supporters.clone()))
+            }
+            Command::Move { .. } | Command::Recruit { .. } | Command::EndTurn => {
+                return Err(CommandError::NotABattle);
+            }
+        };
+        let Some(pending) = game.pending_battle.clone() else {
+            return Err(CommandError::NoPendingBattle);
+        };
+        let best = game.best_defence(&pending);
+        let best_report = game.evaluate_battle(&pending, best.0, &best.1);
+        let (target_is_defending, defence_supporters) = response.unwrap_or_else(|| best.clone());
+        let report = game.evaluate_battle(&pending, target_is_defending, &defence_supporters);
+        let moves = std::iter::once(&pending.initiator)
+            .chain(&pending.attack_supporters)
+            .chain(&defence_supporters)
+            .filter_map(|actor| match actor {
+                BattleActor::Static { .. } => None,
+                BattleActor::Moving { from, to } => Some((*from, *to)),
+            })
+            .collect();
+
+        Ok(BattlePreview {
+            destroyed: if report.outcome == BattleOutcome::AttackerWins {
+                vec![pending.target]
+            } else {
+                vec![]
+            },
+            report,
+            moves,
+            best_response: Command::Defend {
+                target_is_defending: best.0,
+                defence_supporters: best.1,
+            },
+            best_report,
+        })
+    }
+
+    /// Returns the legal response to `pending` giving the defending side the most power
+    ///
+    /// Defence supporters are given the positions that together add the most power, counting
+    /// the defence bonus of each position if it applies, and support from where they stand
+    /// when that adds as much. Moving supporters never share a destination.
+    fn best_defence(&self, pending: &PendingBattle) -> (bool, Vec<BattleActor>) {
+        let target_is_defending = self.check_defend(true, &[]).is_ok();
+        let Ok(options) = self.battle_options(pending.target) else {
+            return (target_is_defending, vec![]);
+        };
+        let actors: Vec<Vec<_>> = options
+            .defence_supporters
+            .iter()
+            .map(|option| option.actors().collect())
+            .collect();
+        let mut positions = HashMap::new();
+        let choices: Vec<Vec<_>> = options
+            .defence_supporters
+            .iter()
+            .zip(&actors)
+            .map(|(option, actors)| {
+                let power = self.unit(self.board[option.coord]).power;
+
+                actors
+                    .iter()
+                    .map(|actor| {
+                        let (position, is_static) = match *actor {
+                            BattleActor::Static { coord } => (coord.as_tile_coord(), true),
+                            BattleActor::Moving { to, .. } => (to, false),
+                        };
+                        let terrain_bonus = if self.ruleset.defence_supporter_terrain {
+                            self.board[position].defence_bonus(&self.ruleset)
+                        } else {
+                            0
+                        };
+                        let next = positions.len();
+                        let index = *positions.entry(position).or_insert(next);
+
+                        // Doubled so that supporting in place breaks ties
+                        let weight = 2 * (u32::from(power) + u32::from(terrain_bonus));
+
+                        (index, weight + u32::from(is_static))
+                    })
+                    .collect()
+            })
+            .collect();
+        let defence_supporters = assign(&choices, positions.len())
+            .into_iter()
+            .zip(actors)
+            .filter_map(|(choice, actors)| Some(actors[choice?]))
+            .collect();
+
+        (target_is_defending, defence_supporters)
+    }
+
+    /// Works out how the pending battle would resolve with the given response, without
+    /// changing the game
+    ///
+    /// The response must already have been validated.
+    pub(crate) fn evaluate_battle(
+        &self,
+        pending: &PendingBattle,
+        target_is_defending: bool,
+        defence_supporters: &[BattleActor],
+    ) -> BattleReport {
+        let participant = |piece: Piece, coord: PieceCoord, side, terrain: bool| Participant {
+            piece,
+            coord,
+            side,
+            power: self.unit(piece).power,
+            terrain_bonus: if terrain {
+                self.board[coord.as_coord()].defence_bonus(&self.ruleset)
+            } else {
+                0
+            },
+        };
+        let attackers = pending
+            .attacker_coords()
+            .map(|coord| participant(self.board[coord], coord, Side::Attack, false));
+        let target = target_is_defending.then(|| {
+            participant(
+                self.board[pending.target],
+                pending.target,
+                Side::Defence,
+                true,
+            )
+        });
+        let defenders = defence_supporters.iter().map(|actor| match actor {
+            BattleActor::Static { coord } => participant(
+                self.board[*coord],
+                *coord,
+                Side::Defence,
+                self.ruleset.defence_supporter_terrain,
+            ),
+            BattleActor::Moving { from, to } => participant(
+                self.board[*from],
+                PieceCoord::new_unchecked(to.as_coord()),
+                Side::Defence,
+                self.ruleset.defence_supporter_terrain,
+            ),
+        });
+        let participants: Vec<_> = attackers.chain(target).chain(defenders).collect();
+        let power = |side| {
+            participants
+                .iter()
+                .filter(|participant| participant.side == side)
+                .map(|participant| participant.total())
+                .fold(0, u8::saturating_add)
+        };
+        let attack_power = power(Side::Attack);
+        let defence_power = power(Side::Defence);
+
+        BattleReport {
+            target: pending.target,
+            participants,
+            attack_power,
+            defence_power,
+            outcome: BattleOutcome::from_powers(attack_power, defence_power),
+        }
+    }
+
+    /// Returns every piece that could initiate, support or defend a battle against `target`
+    ///
+    /// Each option is valid on its own; moving supporters may still conflict with each other
+    /// when combined by choosing the same destination, see
+    /// [`CommandError::SharedDestination`].
+    ///
+    /// The options are also available to the defending player while a battle is pending.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no piece is at `target` or if it belongs to the current player.
+    pub fn battle_options(&self, target: PieceCoord) -> Result<BattleOptions, CommandError> {
+        let target_piece = self.piece_at(target)?;
+
+        if target_piece.owner() == self.current_player {
+            return Err(CommandError::FriendlyTarget(target));
+        }
+
+        let candidates: Vec<_> = self
+            .board
+            .piece_coords()
+            .filter_map(|coord| coord.into_piece_coord(&self.board))
+            .filter(|coord| *coord != target)
+            .map(|coord| {
+                let destinations: Vec<_> = self
+                    .reach(coord.as_coord(), self.board[coord])
+                    .destinations(&self.board)
+                    .filter_map(|to| to.into_tile_coord(&self.board))
+                    .collect();
+
+                (coord, destinations)
+            })
+            .collect();
+        // Supporters are checked as the first of their side, as their index only shows in errors
+        let options_for = |player: Player, role: BattleRole| -> Vec<ActorOption> {
+            let is_valid = |actor| self.validate_actor(actor, target, player, role).is_ok();
+
+            candidates
+                .iter()
+                .filter_map(|(coord, destinations)| {
+                    let in_place = is_valid(BattleActor::Static { coord: *coord });
+                    let destinations: Vec<_> = destinations
+                        .iter()
+                        .copied()
+                        .filter(|to| {
+                            is_valid(BattleActor::Moving {
+                                from: *coord,
+                                to: *to,
+                            })
+                        })
+                        .collect();
+
+                    (in_place || !destinations.is_empty()).then_some(ActorOption {
+                        coord: *coord,
+                        in_place,
+                        destinations,
+                    })
+                })
+                .collect()
+        };
+
+        Ok(BattleOptions {
+            target,
+            target_can_defend: self.unit(target_piece).can_defend()
+                && !target_piece.is_exhausted(self.unit(target_piece)),
+            initiators: options_for(self.current_player, BattleRole::Initiator),
+            attack_supporters: options_for(self.current_player, BattleRole::AttackSupporter(0)),
+            defence_supporters: options_for(-self.current_player, BattleRole::DefenceSupporter(0)),
+        })
+    }
+}
+
+/// Gives each supporter at most one of its choices so that no two choices share a position,
+/// making the total weight of the given choices as great as possible
+///
+/// `choices` lists the position and weight of each choice of every supporter, where positions
+/// are numbered below `positions`, and the index of the choice given to each supporter is
+/// returned. Choices are given along the alternating path that gains the most weight until no
+/// path gains any, which finds a maximum weight matching; ties go to earlier positions.
+fn assign(choices: &[Vec<(usize, u32)>], positions: usize) -> Vec<Option<usize>> {
+    let mut given: Vec<Option<usize>> = vec![None; choices.len()];
+    let mut occupants: Vec<Option<usize>> = vec![None; positions];
+
+    loop {
+        // The greatest gain of a path to each supporter, starting from a supporter without a
+        // choice, and to each position along with the supporter and choice taken to reach it
+        let mut supporter_gains: Vec<Option<i64>> = given
+            .iter()
+            .map(|choice| choice.is_none().then_some(0))
+            .collect();
+        let mut position_gains: Vec<Option<(i64, usize, usize)>> = vec![None; positions];
+
+        for _ in 0..=choices.len() + positions {
+            let mut changed = false;
+
+            for (supporter, supporter_choices) in choices.iter().enumerate() {
+                let Some(gain) = supporter_gains[supporter] else {
+                    continue;
+                };
+
+                for (choice, &(position, weight)) in supporter_choices.iter().enumerate() {
+                    let gain = gain + i64::from(weight);
+
+                    if given[supporter] != Some(choice)
+                        && position_gains[position].is_none_or(|(best, ..)| gain > best)
+                    {
+                        position_gains[position] = Some((gain, supporter, choice));
+                        changed = true;
+                    }
+                }
+            }
+
+            for (position, occupant) in occupants.iter().enumerate() {
+                let (Some(supporter), Some((gain, ..))) = (*occupant, position_gains[position])
+                else {
+                    continue;
+                };
+                let choice = given[supporter].expect("occupants have been given a choice");
+                let gain = gain - i64::from(choices[supporter][choice].1);
+
+                if supporter_gains[supporter].is_none_or(|best| gain > best) {
+                    supporter_gains[supporter] = Some(gain);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        let Some(mut position) = (0..positions)
+            .filter(|position| occupants[*position].is_none())
+            .filter_map(|position| Some((position, position_gains[position]?.0)))
+            .filter(|(_, gain)| *gain > 0)
+            .max_by_key(|(position, gain)| (*gain, Reverse(*position)))
+            .map(|(position, _)| position)
+        else {
+            return given;
+        };
+
+        loop {
+            let (_, supporter, choice) =
+                position_gains[position].expect("every position on the path was reached");
+            let previous = given[supporter].map(|choice| choices[supporter][choice].0);
+
+            given[supporter] = Some(choice);
+            occupants[position] = Some(supporter);
+
+            match previous {
+                Some(previous) => position = previous,
+                None => break,
+            }
+        }
+    }
+}
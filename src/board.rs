@@ -1,9 +1,20 @@
 use crate::{
     coordinates::{Coord, Coordinate, PieceCoord, TileCoord},
     piece::Piece,
-    tile::Tile,
+    tile::{Tile, TileType},
 };
-use std::ops::{Index, IndexMut};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut, Range},
+    str::FromStr,
+};
+
+/// The character representing a missing tile in the board text format
+pub const HOLE_SYMBOL: char = '#';
+/// The character representing the absence of a piece in the piece overlay of the board text
+/// format
+pub const NO_PIECE_SYMBOL: char = '.';
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Board {
@@ -14,7 +25,7 @@ pub struct Board {
 }
 
 impl Board {
-    // TODO: Better new fn
+    /// Creates a board without any tiles
     #[must_use]
     pub const fn new() -> Self {
         Self {
@@ -24,6 +35,36 @@ impl Board {
         }
     }
 
+    /// Creates a board of `ranks` by `files` tiles of type `kind`
+    #[must_use]
+    pub fn filled(ranks: u8, files: u8, kind: TileType) -> Self {
+        Self {
+            map: vec![Some(Tile::new(kind)); usize::from(ranks) * usize::from(files)],
+            ranks,
+            files,
+        }
+    }
+
+    /// Creates a board from a list of ranks, where `None` marks a missing tile
+    ///
+    /// # Errors
+    ///
+    /// All ranks must have the same length, and there can be at most 255 ranks and files.
+    pub fn from_tiles(tiles: Vec<Vec<Option<Tile>>>) -> Result<Self, BoardError> {
+        let ranks = u8::try_from(tiles.len()).map_err(|_| BoardError::TooLarge)?;
+        let files = tiles.first().map_or(0, Vec::len);
+
+        if let Some(rank) = tiles.iter().position(|rank| rank.len() != files) {
+            return Err(BoardError::RaggedRank { rank });
+        }
+
+        Ok(Self {
+            map: tiles.into_iter().flatten().collect(),
+            ranks,
+            files: u8::try_from(files).map_err(|_| BoardError::TooLarge)?,
+        })
+    }
+
     #[must_use]
     pub const fn ranks(&self) -> u8 {
         self.ranks
@@ -36,7 +77,7 @@ impl Board {
 
     #[must_use]
     pub const fn new_coord(&self, rank: u8, file: u8) -> Option<Coord> {
-        if rank <= self.ranks && file <= self.files {
+        if rank < self.ranks && file < self.files {
             Some(Coord::new(rank, file))
         } else {
             None
@@ -58,7 +99,7 @@ impl Board {
         if coord.rank() >= self.ranks || coord.file() >= self.files {
             None
         } else {
-            self.map[(coord.rank() * self.files + coord.file()) as usize]
+            self.map[self.map_index(coord)]
         }
     }
 
@@ -69,12 +110,39 @@ impl Board {
     /// `coord` must index a tile that exists.
     pub fn get_mut(&mut self, coord: Coord) -> Option<&mut Tile> {
         if self.coord_in_bounds(coord) {
-            self.map[(coord.rank() * self.files + coord.file()) as usize].as_mut()
+            let index = self.map_index(coord);
+
+            self.map[index].as_mut()
         } else {
             None
         }
     }
 
+    /// Returns the position of `coord` in the map, computed in `usize` so that large boards do
+    /// not overflow
+    fn map_index(&self, coord: impl Coordinate) -> usize {
+        usize::from(coord.rank()) * usize::from(self.files) + usize::from(coord.file())
+    }
+
+    /// Returns the position of `coord` in the map for indexing the board
+    ///
+    /// # Panics
+    ///
+    /// Panics if `coord` is outside the board, such as a coord made for a larger board, rather
+    /// than indexing whichever tile it would alias.
+    fn index_of(&self, coord: impl Coordinate) -> usize {
+        assert!(
+            coord.rank() < self.ranks && coord.file() < self.files,
+            "indexed rank {}, file {} outside a board of {} ranks and {} files",
+            coord.rank(),
+            coord.file(),
+            self.ranks,
+            self.files
+        );
+
+        self.map_index(coord)
+    }
+
     pub fn tiles(&self) -> impl Iterator<Item = Tile> {
         self.map.iter().filter_map(|tile_option| *tile_option)
     }
@@ -99,6 +167,39 @@ impl Board {
         )
     }
 
+    /// Returns the coords of every tile within `distance` of `centre`, including `centre`
+    pub fn coords_within(&self, centre: Coord, distance: u16) -> impl Iterator<Item = Coord> {
+        centre
+            .within(distance)
+            .filter(|coord| self.get(*coord).is_some())
+    }
+
+    /// Returns the coords of every tile exactly `distance` from `centre`
+    pub fn coords_at(&self, centre: Coord, distance: u16) -> impl Iterator<Item = Coord> {
+        centre
+            .ring(distance)
+            .filter(|coord| self.get(*coord).is_some())
+    }
+
+    /// Returns the coords of every tile whose distance from `centre` is within `range`, such as
+    /// the attack range of a piece, nearest first
+    pub fn coords_in_range(&self, centre: Coord, range: Range<u8>) -> impl Iterator<Item = Coord> {
+        range.flat_map(move |distance| self.coords_at(centre, u16::from(distance)))
+    }
+
+    /// Returns the coords of every tile in the rectangle with corners `from` and `to`, inclusive
+    pub fn coords_in_rectangle(&self, from: Coord, to: Coord) -> impl Iterator<Item = Coord> {
+        from.rectangle(to)
+            .filter(|coord| self.get(*coord).is_some())
+    }
+
+    pub fn tile_coords(&self) -> impl Iterator<Item = Coord> {
+        (0..self.ranks)
+            .flat_map(|rank| (0..self.files).map(move |file| Coord::new(rank, file)))
+            .zip(self.map.iter())
+            .filter_map(|(coord, tile_option)| tile_option.map(|_| coord))
+    }
+
     pub fn piece_coords(&self) -> impl Iterator<Item = Coord> {
         (0..self.ranks)
             .flat_map(|rank| (0..self.files).map(move |file| Coord::new(rank, file)))
@@ -119,11 +220,157 @@ impl Default for Board {
     }
 }
 
+/// Formats the board in the board text format
+///
+/// Each rank is written on its own line with one character per tile, as given by
+/// [`TileType::symbol`] or [`HOLE_SYMBOL`] for a missing tile. If the board holds any pieces,
+/// a blank line and a piece overlay of the same shape follow, using [`Piece::symbol`] or
+/// [`NO_PIECE_SYMBOL`]. Exhaustion and tile control are not part of the format.
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let ranks = || self.map.chunks(usize::from(self.files).max(1));
+
+        for rank in ranks() {
+            let line: String = rank
+                .iter()
+                .map(|tile_option| tile_option.map_or(HOLE_SYMBOL, |tile| tile.kind.symbol()))
+                .collect();
+
+            writeln!(f, "{line}")?;
+        }
+
+        if self.piece_coords().next().is_some() {
+            writeln!(f)?;
+
+            for rank in ranks() {
+                let line: String = rank
+                    .iter()
+                    .map(|tile_option| {
+                        tile_option
+                            .and_then(|tile| tile.piece_option)
+                            .map_or(NO_PIECE_SYMBOL, Piece::symbol)
+                    })
+                    .collect();
+
+                writeln!(f, "{line}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a board from the format written by its [`Display`] implementation
+///
+/// Pieces read from the overlay are ready to act.
+impl FromStr for Board {
+    type Err = BoardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(|line| line.trim_end_matches('\r'));
+        let tile_lines: Vec<&str> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+        let piece_lines: Vec<&str> = lines.take_while(|line| !line.is_empty()).collect();
+
+        let tiles = tile_lines
+            .iter()
+            .enumerate()
+            .map(|(rank, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(file, symbol)| match symbol {
+                        HOLE_SYMBOL => Ok(None),
+                        _ => TileType::from_symbol(symbol)
+                            .map(|kind| Some(Tile::new(kind)))
+                            .ok_or(BoardError::UnknownSymbol { rank, file, symbol }),
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        let mut board = Self::from_tiles(tiles)?;
+
+        if piece_lines.is_empty() {
+            return Ok(board);
+        }
+
+        if piece_lines.len() != usize::from(board.ranks) {
+            return Err(BoardError::OverlayMismatch);
+        }
+
+        for (rank, line) in piece_lines.iter().enumerate() {
+            if line.chars().count() != usize::from(board.files) {
+                return Err(BoardError::OverlayMismatch);
+            }
+
+            for (file, symbol) in line.chars().enumerate() {
+                if symbol == NO_PIECE_SYMBOL {
+                    continue;
+                }
+
+                let piece = Piece::from_symbol(symbol).ok_or(BoardError::UnknownSymbol {
+                    rank,
+                    file,
+                    symbol,
+                })?;
+                let coord = Coord::new(
+                    u8::try_from(rank).map_err(|_| BoardError::TooLarge)?,
+                    u8::try_from(file).map_err(|_| BoardError::TooLarge)?,
+                );
+
+                board
+                    .get_mut(coord)
+                    .ok_or(BoardError::PieceOnHole { rank, file })?
+                    .piece_option = Some(piece);
+            }
+        }
+
+        Ok(board)
+    }
+}
+
+/// The reason a board could not be constructed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BoardError {
+    /// The board has more than 255 ranks or files
+    TooLarge,
+    /// A rank has a different length to the first rank
+    RaggedRank { rank: usize },
+    /// A character is not a tile or piece symbol
+    UnknownSymbol {
+        rank: usize,
+        file: usize,
+        symbol: char,
+    },
+    /// The piece overlay does not have the same shape as the tiles
+    OverlayMismatch,
+    /// The piece overlay places a piece on a missing tile
+    PieceOnHole { rank: usize, file: usize },
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge => write!(f, "board has more than 255 ranks or files"),
+            Self::RaggedRank { rank } => {
+                write!(f, "rank {rank} has a different length to the first rank")
+            }
+            Self::UnknownSymbol { rank, file, symbol } => {
+                write!(f, "unknown symbol {symbol:?} at rank {rank}, file {file}")
+            }
+            Self::OverlayMismatch => write!(f, "piece overlay does not match the board shape"),
+            Self::PieceOnHole { rank, file } => {
+                write!(f, "piece on missing tile at rank {rank}, file {file}")
+            }
+        }
+    }
+}
+
+impl Error for BoardError {}
+
 impl Index<Coord> for Board {
     type Output = Tile;
 
     fn index(&self, index: Coord) -> &Self::Output {
-        self.map[(index.rank() * self.files + index.file()) as usize]
+        self.map[self.index_of(index)]
             .as_ref()
             .expect("Indexed invalid tile")
     }
@@ -131,9 +378,9 @@ impl Index<Coord> for Board {
 
 impl IndexMut<Coord> for Board {
     fn index_mut(&mut self, index: Coord) -> &mut Self::Output {
-        self.map[(index.rank() * self.files + index.file()) as usize]
-            .as_mut()
-            .expect("Indexed invalid tile")
+        let index = self.index_of(index);
+
+        self.map[index].as_mut().expect("Indexed invalid tile")
     }
 }
 
@@ -141,7 +388,7 @@ impl Index<TileCoord> for Board {
     type Output = Tile;
 
     fn index(&self, index: TileCoord) -> &Self::Output {
-        self.map[(index.rank() * self.files + index.file()) as usize]
+        self.map[self.index_of(index)]
             .as_ref()
             .expect("Indexed invalid tile")
     }
@@ -149,9 +396,9 @@ impl Index<TileCoord> for Board {
 
 impl IndexMut<TileCoord> for Board {
     fn index_mut(&mut self, index: TileCoord) -> &mut Self::Output {
-        self.map[(index.rank() * self.files + index.file()) as usize]
-            .as_mut()
-            .expect("Indexed invalid tile")
+        let index = self.index_of(index);
+
+        self.map[index].as_mut().expect("Indexed invalid tile")
     }
 }
 
@@ -159,7 +406,7 @@ impl Index<PieceCoord> for Board {
     type Output = Piece;
 
     fn index(&self, index: PieceCoord) -> &Self::Output {
-        self.map[(index.rank() * self.files + index.file()) as usize]
+        self.map[self.index_of(index)]
             .as_ref()
             .expect("PieceCoord always indexes a valid tile")
             .piece_option
@@ -170,7 +417,9 @@ impl Index<PieceCoord> for Board {
 
 impl IndexMut<PieceCoord> for Board {
     fn index_mut(&mut self, index: PieceCoord) -> &mut Self::Output {
-        self.map[(index.rank() * self.files + index.file()) as usize]
+        let index = self.index_of(index);
+
+        self.map[index]
             .as_mut()
             .expect("PieceCoord always indexes a valid tile")
             .piece_option
@@ -0,0 +1,227 @@
+use crate::{
+    Game,
+    board::Board,
+    coordinates::Coord,
+    history::{History, UndoPolicy},
+    phase::Phase,
+    piece::Piece,
+    player::Player,
+    ruleset::{Ruleset, RulesetError},
+    unit::Units,
+    victory::{GameStatus, VictoryConditions},
+};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+};
+
+/// Sets up a [`Game`] from a chosen position
+///
+/// The built game starts in the command phase of turn 1, after the current player has
+/// collected their income.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameBuilder {
+    board: Board,
+    current_player: Player,
+    /// The money of each player, if it differs from the starting money of the ruleset
+    money: [Option<u8>; 2],
+    pieces: Vec<(Coord, Piece)>,
+    captures: Vec<(Coord, Player)>,
+    undo_policy: UndoPolicy,
+    ruleset: Ruleset,
+}
+
+impl GameBuilder {
+    /// Creates a builder for a game on `board`, keeping any pieces already on it
+    ///
+    /// The game uses the [default rules](Ruleset::default) unless others are given.
+    #[must_use]
+    pub fn new(board: Board) -> Self {
+        Self {
+            board,
+            current_player: Player::P1,
+            money: [None, None],
+            pieces: vec![],
+            captures: vec![],
+            undo_policy: UndoPolicy::CASUAL,
+            ruleset: Ruleset::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn current_player(mut self, player: Player) -> Self {
+        self.current_player = player;
+        self
+    }
+
+    /// Gives `player` `money` instead of the starting money of the ruleset, before any income
+    /// is collected
+    #[must_use]
+    pub const fn money(mut self, player: Player, money: u8) -> Self {
+        match player {
+            Player::P1 => self.money[0] = Some(money),
+            Player::P2 => self.money[1] = Some(money),
+        }
+        self
+    }
+
+    /// Places `piece` at `coord`, keeping the actions it has taken
+    ///
+    /// Every piece is given a new id when the game is built.
+    #[must_use]
+    pub fn piece(mut self, coord: Coord, piece: Piece) -> Self {
+        self.pieces.push((coord, piece));
+        self
+    }
+
+    /// Gives `player` control of the town or city at `coord`
+    #[must_use]
+    pub fn capture(mut self, coord: Coord, player: Player) -> Self {
+        self.captures.push((coord, player));
+        self
+    }
+
+    #[must_use]
+    pub const fn undo_policy(mut self, undo_policy: UndoPolicy) -> Self {
+        self.undo_policy = undo_policy;
+        self
+    }
+
+    /// Sets the rules the game is played with, replacing any units or victory conditions
+    /// given so far
+    #[must_use]
+    pub fn ruleset(mut self, ruleset: Ruleset) -> Self {
+        self.ruleset = ruleset;
+        self
+    }
+
+    /// Sets the definitions of the piece types that can be used in the game
+    #[must_use]
+    pub fn units(mut self, units: Units) -> Self {
+        self.ruleset.units = units;
+        self
+    }
+
+    #[must_use]
+    pub fn victory_conditions(mut self, victory_conditions: VictoryConditions) -> Self {
+        self.ruleset.victory_conditions = victory_conditions;
+        self
+    }
+
+    /// Creates the game
+    ///
+    /// Pieces are given ids in board order. Pieces already on the board count as fielded, so
+    /// losing them all eliminates their owner.
+    ///
+    /// # Errors
+    ///
+    /// The ruleset must be [valid](Ruleset::validate), pieces must be placed on existing,
+    /// unoccupied tiles and have a type defined by the units of the game, only towns and cities
+    /// can be captured, and objective tiles must exist.
+    pub fn build(self) -> Result<Game, BuildError> {
+        let mut board = self.board;
+
+        self.ruleset.validate().map_err(BuildError::Ruleset)?;
+
+        if let Some(objectives) = &self.ruleset.victory_conditions.objectives
+            && let Some(coord) = objectives
+                .tiles
+                .iter()
+                .find(|coord| board.get(**coord).is_none())
+        {
+            return Err(BuildError::MissingTile(*coord));
+        }
+
+        for (coord, piece) in self.pieces {
+            let tile = board.get_mut(coord).ok_or(BuildError::MissingTile(coord))?;
+
+            if tile.piece_option.is_some() {
+                return Err(BuildError::TileOccupied(coord));
+            }
+
+            tile.piece_option = Some(piece);
+        }
+
+        if let Some(coord) = board.piece_coords().find(|coord| {
+            board[*coord]
+                .piece_option
+                .is_some_and(|piece| self.ruleset.units.get(piece.kind()).is_none())
+        }) {
+            return Err(BuildError::UnknownPieceType(coord));
+        }
+
+        for (coord, player) in self.captures {
+            let tile = board.get_mut(coord).ok_or(BuildError::MissingTile(coord))?;
+
+            if !tile.is_capturable() {
+                return Err(BuildError::NotCapturable(coord));
+            }
+
+            tile.capture(player);
+        }
+
+        let mut game = Game {
+            board,
+            current_player: self.current_player,
+            phase: Phase::Command,
+            turn: 1,
+            money: self
+                .money
+                .map(|money| money.unwrap_or(self.ruleset.starting_money)),
+            pending_battle: None,
+            log: vec![],
+            history: History::new(),
+            undo_policy: self.undo_policy,
+            ruleset: Arc::new(self.ruleset),
+            status: GameStatus::Ongoing,
+            has_fielded: [false, false],
+            objective_turns: [0, 0],
+            next_piece_id: 0,
+        };
+
+        game.assign_piece_ids();
+        game.collect_income();
+        game.update_status();
+
+        Ok(game)
+    }
+}
+
+/// The reason a [`GameBuilder`] could not create a game
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// No tile exists at the coord
+    MissingTile(Coord),
+    /// More than one piece was placed on the tile at the coord
+    TileOccupied(Coord),
+    /// The tile at the coord is not a town or city
+    NotCapturable(Coord),
+    /// The piece at the coord has a type without a unit definition
+    UnknownPieceType(Coord),
+    /// The ruleset cannot be played
+    Ruleset(RulesetError),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTile(coord) => write!(f, "no tile exists at {coord}"),
+            Self::TileOccupied(coord) => write!(f, "tile at {coord} already holds a piece"),
+            Self::NotCapturable(coord) => write!(f, "tile at {coord} cannot be captured"),
+            Self::UnknownPieceType(coord) => {
+                write!(f, "piece at {coord} has an undefined type")
+            }
+            Self::Ruleset(error) => write!(f, "ruleset is invalid: {error}"),
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Ruleset(error) => Some(error),
+            _ => None,
+        }
+    }
+}
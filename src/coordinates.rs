@@ -1,12 +1,16 @@
 use crate::{board::Board, coordinates::seal::Sealed};
+use std::fmt::{self, Display, Formatter};
 
 pub trait Coordinate: Copy + Eq + PartialEq + Sealed {
+    #[must_use]
     fn rank(self) -> u8;
+    #[must_use]
     fn file(self) -> u8;
-    fn distance(self, other: impl Coordinate) -> u8;
+    #[must_use]
+    fn distance(self, other: impl Coordinate) -> u16;
 }
 
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Coord {
     rank: u8,
     file: u8,
@@ -18,26 +22,70 @@ impl Coord {
         Self { rank, file }
     }
 
+    /// Returns the orthogonally adjacent coords, omitting any that would underflow or overflow
     #[must_use]
-    pub const fn adjacent(self) -> [Self; 4] {
+    pub fn adjacent(self) -> Vec<Self> {
         [
-            Self {
-                rank: self.rank + 1,
-                file: self.file,
-            },
-            Self {
-                rank: self.rank - 1,
-                file: self.file,
-            },
-            Self {
-                rank: self.rank,
-                file: self.file + 1,
-            },
-            Self {
-                rank: self.rank,
-                file: self.file - 1,
-            },
+            self.rank
+                .checked_add(1)
+                .map(|rank| Self::new(rank, self.file)),
+            self.rank
+                .checked_sub(1)
+                .map(|rank| Self::new(rank, self.file)),
+            self.file
+                .checked_add(1)
+                .map(|file| Self::new(self.rank, file)),
+            self.file
+                .checked_sub(1)
+                .map(|file| Self::new(self.rank, file)),
         ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Returns every coord within `distance` of this one, including itself, in rank then file
+    /// order and omitting any that would underflow or overflow
+    pub fn within(self, distance: u16) -> impl Iterator<Item = Self> {
+        let reach = clamp_to_u8(distance);
+
+        (self.rank.saturating_sub(reach)..=self.rank.saturating_add(reach)).flat_map(move |rank| {
+            let spare = clamp_to_u8(distance - u16::from(rank.abs_diff(self.rank)));
+
+            (self.file.saturating_sub(spare)..=self.file.saturating_add(spare))
+                .map(move |file| Self::new(rank, file))
+        })
+    }
+
+    /// Returns every coord exactly `distance` from this one, in rank then file order and
+    /// omitting any that would underflow or overflow
+    pub fn ring(self, distance: u16) -> impl Iterator<Item = Self> {
+        let reach = clamp_to_u8(distance);
+
+        (self.rank.saturating_sub(reach)..=self.rank.saturating_add(reach)).flat_map(move |rank| {
+            let spare = distance - u16::from(rank.abs_diff(self.rank));
+            let offset = |file: fn(u8, u8) -> Option<u8>| {
+                u8::try_from(spare)
+                    .ok()
+                    .and_then(|spare| file(self.file, spare))
+            };
+            let before = offset(u8::checked_sub);
+            let after = (spare != 0).then(|| offset(u8::checked_add)).flatten();
+
+            before
+                .into_iter()
+                .chain(after)
+                .map(move |file| Self::new(rank, file))
+        })
+    }
+
+    /// Returns every coord in the rectangle with corners at this coord and `other`, inclusive,
+    /// in rank then file order
+    pub fn rectangle(self, other: Self) -> impl Iterator<Item = Self> {
+        let files = self.file.min(other.file)..=self.file.max(other.file);
+
+        (self.rank.min(other.rank)..=self.rank.max(other.rank))
+            .flat_map(move |rank| files.clone().map(move |file| Self::new(rank, file)))
     }
 
     #[must_use]
@@ -52,25 +100,31 @@ impl Coord {
 }
 
 impl Coordinate for Coord {
-    #[must_use]
     fn rank(self) -> u8 {
         self.rank
     }
 
-    #[must_use]
     fn file(self) -> u8 {
         self.file
     }
 
     /// Returns the distance between two coordinates in tiles through orthogonal connections
-    #[must_use]
-    fn distance(self, other: impl Coordinate) -> u8 {
-        self.rank.abs_diff(other.rank()) + self.file.abs_diff(other.file())
+    ///
+    /// The distance is wider than the coordinates, as opposite corners of the largest board
+    /// are 508 tiles apart.
+    fn distance(self, other: impl Coordinate) -> u16 {
+        u16::from(self.rank.abs_diff(other.rank())) + u16::from(self.file.abs_diff(other.file()))
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "rank {}, file {}", self.rank(), self.file())
     }
 }
 
 /// A `TileCoord` will always refer to a tile
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TileCoord(Coord);
 
 impl TileCoord {
@@ -85,6 +139,13 @@ impl TileCoord {
         Some(Self(coord))
     }
 
+    /// Creates a `TileCoord` without checking it against a board, for coords that referred to
+    /// a tile when they were recorded
+    #[must_use]
+    pub(crate) const fn new_unchecked(coord: Coord) -> Self {
+        Self(coord)
+    }
+
     #[must_use]
     pub const fn as_coord(self) -> Coord {
         self.0
@@ -100,12 +161,18 @@ impl Coordinate for TileCoord {
         self.0.file
     }
 
-    fn distance(self, other: impl Coordinate) -> u8 {
+    fn distance(self, other: impl Coordinate) -> u16 {
         self.0.distance(other)
     }
 }
+
+impl Display for TileCoord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "rank {}, file {}", self.rank(), self.file())
+    }
+}
 /// A `PieceCoord` will always refer to a tile with a piece on it
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub struct PieceCoord(Coord);
 
 impl PieceCoord {
@@ -120,6 +187,13 @@ impl PieceCoord {
         Some(Self(coord))
     }
 
+    /// Creates a `PieceCoord` without checking it against a board, for coords that referred to
+    /// a piece when they were recorded
+    #[must_use]
+    pub(crate) const fn new_unchecked(coord: Coord) -> Self {
+        Self(coord)
+    }
+
     #[must_use]
     pub const fn as_coord(self) -> Coord {
         self.0
@@ -140,11 +214,22 @@ impl Coordinate for PieceCoord {
         self.0.file
     }
 
-    fn distance(self, other: impl Coordinate) -> u8 {
+    fn distance(self, other: impl Coordinate) -> u16 {
         self.0.distance(other)
     }
 }
 
+impl Display for PieceCoord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "rank {}, file {}", self.rank(), self.file())
+    }
+}
+
+/// Converts a distance to a number of ranks or files, saturating at the largest
+fn clamp_to_u8(distance: u16) -> u8 {
+    u8::try_from(distance).unwrap_or(u8::MAX)
+}
+
 mod seal {
     use crate::coordinates::{Coord, PieceCoord, TileCoord};
 
@@ -0,0 +1,140 @@
+use crate::{
+    coordinates::{PieceCoord, TileCoord},
+    piece::PieceType,
+    player::Player,
+    victory::GameStatus,
+};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::Range,
+};
+
+/// The reason a command was rejected by a [`Game`](crate::Game)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CommandError {
+    /// The game has ended
+    GameOver(GameStatus),
+    /// A battle is awaiting the response of the defending player
+    BattlePending,
+    /// There is no battle awaiting a response
+    NoPendingBattle,
+    /// The command does not declare or respond to a battle
+    NotABattle,
+    /// No tile exists at the coord, which was created for another board
+    NoTile(TileCoord),
+    /// No piece is at the coord, which is out of date or was created for another board
+    NoPiece(PieceCoord),
+    /// The destination tile already holds a piece
+    TileOccupied(TileCoord),
+    /// The piece has already acted this turn
+    PieceExhausted(PieceCoord),
+    /// The piece cannot move after acting this turn
+    AlreadyActed(PieceCoord),
+    /// The piece does not belong to the player issuing the command
+    WrongOwner { coord: PieceCoord, owner: Player },
+    /// The destination is further away than the piece could move even over the cheapest terrain
+    OutOfSpeed { distance: u16, speed: u8 },
+    /// Every route to the destination is blocked by missing tiles, impassable terrain or enemy
+    /// pieces, or costs more than the speed of the piece
+    NoPath(TileCoord),
+    /// The target is not within the attack range of the piece
+    OutOfRange { distance: u16, range: Range<u8> },
+    /// The player cannot afford the piece
+    InsufficientMoney { cost: u8, money: u8 },
+    /// The piece type has no unit definition in the game
+    UnknownPieceType(PieceType),
+    /// The tile is not a recruitment point of the player
+    NotRecruitmentTile(TileCoord),
+    /// The targeted piece belongs to the attacking player
+    FriendlyTarget(PieceCoord),
+    /// The piece is unable to initiate a battle
+    CannotInitiate(PieceCoord),
+    /// The piece is unable to defend itself
+    CannotDefend(PieceCoord),
+    /// The piece is unable to support a battle in the requested manner
+    CannotSupport(PieceCoord),
+    /// The piece takes part in the battle more than once
+    RepeatedActor(PieceCoord),
+    /// More than one piece in the battle moves to the tile
+    SharedDestination(TileCoord),
+    /// A participant of a battle failed validation
+    InvalidActor { role: BattleRole, reason: Box<Self> },
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GameOver(status) => write!(f, "the game is over: {status}"),
+            Self::BattlePending => write!(f, "a battle is awaiting the defending player"),
+            Self::NoPendingBattle => write!(f, "no battle is awaiting a response"),
+            Self::NotABattle => write!(f, "command is not part of a battle"),
+            Self::NoTile(coord) => write!(f, "no tile exists at {coord}"),
+            Self::NoPiece(coord) => write!(f, "no piece is at {coord}"),
+            Self::TileOccupied(coord) => write!(f, "tile at {coord} is occupied"),
+            Self::PieceExhausted(coord) => write!(f, "piece at {coord} is exhausted"),
+            Self::AlreadyActed(coord) => {
+                write!(f, "piece at {coord} cannot move after acting")
+            }
+            Self::WrongOwner { coord, owner } => {
+                write!(f, "piece at {coord} belongs to {owner}")
+            }
+            Self::OutOfSpeed { distance, speed } => {
+                write!(f, "distance {distance} exceeds speed {speed}")
+            }
+            Self::NoPath(coord) => write!(f, "no unblocked path within speed to {coord}"),
+            Self::OutOfRange { distance, range } => {
+                write!(f, "distance {distance} is outside of range {range:?}")
+            }
+            Self::InsufficientMoney { cost, money } => {
+                write!(f, "cost {cost} exceeds available money {money}")
+            }
+            Self::UnknownPieceType(piece_type) => {
+                write!(f, "piece type {:?} is not defined", piece_type.symbol())
+            }
+            Self::NotRecruitmentTile(coord) => {
+                write!(f, "tile at {coord} is not a recruitment point")
+            }
+            Self::FriendlyTarget(coord) => write!(f, "piece at {coord} is friendly"),
+            Self::CannotInitiate(coord) => {
+                write!(f, "piece at {coord} cannot initiate a battle")
+            }
+            Self::CannotDefend(coord) => write!(f, "piece at {coord} cannot defend"),
+            Self::CannotSupport(coord) => {
+                write!(f, "piece at {coord} cannot support this battle")
+            }
+            Self::RepeatedActor(coord) => {
+                write!(
+                    f,
+                    "piece at {coord} takes part in the battle more than once"
+                )
+            }
+            Self::SharedDestination(coord) => {
+                write!(f, "more than one piece moves to {coord}")
+            }
+            Self::InvalidActor { role, reason } => write!(f, "{role}: {reason}"),
+        }
+    }
+}
+
+impl Error for CommandError {}
+
+/// The position of a participant within a battle command
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BattleRole {
+    Initiator,
+    /// Index into the attack supporters
+    AttackSupporter(usize),
+    /// Index into the defence supporters
+    DefenceSupporter(usize),
+}
+
+impl Display for BattleRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Initiator => write!(f, "initiator"),
+            Self::AttackSupporter(index) => write!(f, "attack supporter {index}"),
+            Self::DefenceSupporter(index) => write!(f, "defence supporter {index}"),
+        }
+    }
+}
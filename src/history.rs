@@ -0,0 +1,155 @@
+use crate::{
+    Game,
+    actions::{Command, LoggedCommand},
+};
+use std::{mem, sync::Arc};
+
+/// Which commands can be undone
+///
+/// Moves and recruitments can always be undone until the turn ends. Commands that cannot be
+/// undone also clear the undo history, so nothing before them can be undone either.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UndoPolicy {
+    /// Whether battles can be undone, revealing their outcome to the player in advance
+    pub battles: bool,
+    /// Whether ending the turn can be undone, returning control to the previous player
+    pub end_turn: bool,
+}
+
+impl UndoPolicy {
+    /// Allows every command to be undone
+    pub const CASUAL: Self = Self {
+        battles: true,
+        end_turn: true,
+    };
+    /// Only allows moves and recruitments within the current turn to be undone
+    pub const COMPETITIVE: Self = Self {
+        battles: false,
+        end_turn: false,
+    };
+
+    #[must_use]
+    pub const fn allows(self, command: &Command) -> bool {
+        match command {
+            Command::Move { .. } | Command::Recruit { .. } => true,
+            Command::Battle { .. } | Command::Defend { .. } => self.battles,
+            Command::EndTurn => self.end_turn,
+        }
+    }
+}
+
+impl Default for UndoPolicy {
+    fn default() -> Self {
+        Self::CASUAL
+    }
+}
+
+/// The undo and redo stacks of a [`Game`]
+///
+/// Snapshots are copies of the game without their own log or history, sharing its ruleset.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct History {
+    /// The game before each undoable command, most recent last
+    undo: Vec<Game>,
+    /// The game after each undone command along with the command, most recently undone last
+    redo: Vec<(Game, LoggedCommand)>,
+}
+
+impl History {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            undo: vec![],
+            redo: vec![],
+        }
+    }
+}
+
+impl Game {
+    #[must_use]
+    pub const fn undo_policy(&self) -> UndoPolicy {
+        self.undo_policy
+    }
+
+    /// Sets which commands can be undone from now on
+    pub const fn set_undo_policy(&mut self, undo_policy: UndoPolicy) {
+        self.undo_policy = undo_policy;
+    }
+
+    #[must_use]
+    pub const fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    #[must_use]
+    pub const fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    /// Reverts the last undoable command, returning it
+    pub fn undo(&mut self) -> Option<LoggedCommand> {
+        let before = self.history.undo.pop()?;
+        let logged = self.log.pop()?;
+        let after = self.snapshot();
+
+        self.restore(before);
+        self.history.redo.push((after, logged.clone()));
+
+        Some(logged)
+    }
+
+    /// Reapplies the last undone command, returning it
+    pub fn redo(&mut self) -> Option<LoggedCommand> {
+        let (after, logged) = self.history.redo.pop()?;
+        let before = self.snapshot();
+
+        self.restore(after);
+        self.history.undo.push(before);
+        self.log.push(logged.clone());
+
+        Some(logged)
+    }
+
+    /// Records that a command was applied to the game captured in `before`, which is `None`
+    /// if the undo policy does not allow the command to be undone
+    pub(crate) fn record(&mut self, before: Option<Self>) {
+        self.history.redo.clear();
+
+        match before {
+            Some(before) => self.history.undo.push(before),
+            None => self.history.undo.clear(),
+        }
+    }
+
+    /// Returns a copy of the game without its log or history
+    pub(crate) fn snapshot(&self) -> Self {
+        Self {
+            board: self.board.clone(),
+            current_player: self.current_player,
+            phase: self.phase,
+            turn: self.turn,
+            money: self.money,
+            pending_battle: self.pending_battle.clone(),
+            log: vec![],
+            history: History::new(),
+            undo_policy: self.undo_policy,
+            ruleset: Arc::clone(&self.ruleset),
+            status: self.status,
+            has_fielded: self.has_fielded,
+            objective_turns: self.objective_turns,
+            next_piece_id: self.next_piece_id,
+        }
+    }
+
+    /// Replaces the state of the game with `snapshot`, keeping the log, history and policy
+    fn restore(&mut self, snapshot: Self) {
+        let log = mem::take(&mut self.log);
+        let history = mem::take(&mut self.history);
+        let undo_policy = self.undo_policy;
+
+        *self = snapshot;
+        self.log = log;
+        self.history = history;
+        self.undo_policy = undo_policy;
+    }
+}
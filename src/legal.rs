@@ -0,0 +1,118 @@
+use crate::{
+    Game,
+    actions::{BattleActor, Command},
+    coordinates::PieceCoord,
+};
+
+impl Game {
+    /// Returns every legal command of the acting player
+    ///
+    /// Battles are only listed with their initiator and without supporters; see
+    /// [`Self::legal_commands_for`]. While a battle is pending, the responses without defence
+    /// supporters are listed instead, and supporters can be found with
+    /// [`Self::battle_options`].
+    #[must_use]
+    pub fn legal_commands(&self) -> Vec<Command> {
+        if self.pending_battle.is_some() {
+            return [false, true]
+                .into_iter()
+                .filter(|target_is_defending| self.can_do_defend(*target_is_defending, &[]))
+                .map(|target_is_defending| Command::Defend {
+                    target_is_defending,
+                    defence_supporters: vec![],
+                })
+                .collect();
+        }
+
+        if self.check_can_command().is_err() {
+            return vec![];
+        }
+
+        let piece_commands = self
+            .board
+            .piece_coords()
+            .filter_map(|coord| coord.into_piece_coord(&self.board))
+            .flat_map(|coord| self.legal_commands_for(coord));
+        let recruits = self.board.tile_coords().flat_map(|coord| {
+            self.ruleset.units.types().filter_map(move |piece_type| {
+                let coord = coord.into_tile_coord(&self.board)?;
+
+                self.can_do_recruit(piece_type, coord)
+                    .then_some(Command::Recruit { piece_type, coord })
+            })
+        });
+
+        piece_commands
+            .chain(recruits)
+            .chain(std::iter::once(Command::EndTurn))
+            .collect()
+    }
+
+    /// Returns every legal move of the piece at `coord` and every battle it can initiate
+    ///
+    /// Battles are listed once per initiator position and without supporters.
+    #[must_use]
+    pub fn legal_commands_for(&self, coord: PieceCoord) -> Vec<Command> {
+        let Ok(piece) = self.piece_at(coord) else {
+            return vec![];
+        };
+
+        let unit = self.unit(piece);
+
+        if self.check_can_command().is_err()
+            || piece.owner() != self.current_player
+            || piece.is_exhausted(unit)
+        {
+            return vec![];
+        }
+
+        let destinations: Vec<_> = if piece.can_move(unit) {
+            self.reach(coord.as_coord(), piece)
+                .destinations(&self.board)
+                .filter_map(|to| to.into_tile_coord(&self.board))
+                .collect()
+        } else {
+            vec![]
+        };
+        let mut commands: Vec<_> = destinations
+            .iter()
+            .map(|to| Command::Move {
+                from: coord,
+                to: *to,
+            })
+            .collect();
+
+        if !unit.can_initiate() {
+            return commands;
+        }
+
+        let mut initiators = vec![BattleActor::Static { coord }];
+
+        if unit.can_support(true, true) {
+            initiators.extend(destinations.iter().map(|to| BattleActor::Moving {
+                from: coord,
+                to: *to,
+            }));
+        }
+
+        for initiator in initiators {
+            let position = match initiator {
+                BattleActor::Static { coord } => coord.as_coord(),
+                BattleActor::Moving { to, .. } => to.as_coord(),
+            };
+            let targets = self
+                .board
+                .coords_in_range(position, unit.range.clone())
+                .filter_map(|target| target.into_piece_coord(&self.board))
+                .filter(|target| self.board[*target].owner() != self.current_player);
+
+            commands.extend(targets.map(|target| Command::Battle {
+                target,
+                initiator,
+                attack_supporters: vec![],
+            }));
+        }
+
+        commands
+    }
+}
@@ -1,34 +1,93 @@
 use crate::coordinates::{PieceCoord, TileCoord};
 use crate::{
-    actions::{BattleActor, Command},
+    actions::{BattleActor, Command, LoggedCommand},
+    battle::{BattleOutcome, BattleReport, PendingBattle},
     board::Board,
-    coordinates::Coordinate,
-    piece::{Piece, PieceType},
+    coordinates::{Coord, Coordinate},
+    error::{BattleRole, CommandError},
+    history::{History, UndoPolicy},
+    pathing::Reach,
+    phase::Phase,
+    piece::{Actions, BattleAction, Piece, PieceId, PieceType},
     player::Player,
+    ruleset::Ruleset,
+    tile::Tile,
+    unit::{UnitDef, Units},
+    victory::GameStatus,
 };
+use std::{collections::HashSet, sync::Arc};
 
 pub mod actions;
+pub mod battle;
 pub mod board;
+pub mod builder;
 pub mod coordinates;
+pub mod error;
+pub mod history;
+mod legal;
+mod pathing;
+pub mod phase;
 pub mod piece;
 pub mod player;
+pub mod replay;
+pub mod ruleset;
+pub mod save;
+pub mod supply;
 pub mod tile;
+pub mod unit;
+pub mod victory;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
     board: Board,
     current_player: Player,
+    phase: Phase,
+    /// The number of the current turn, counting each player's turn separately from 1
+    turn: u32,
     money: [u8; 2],
+    pending_battle: Option<PendingBattle>,
+    /// Every command accepted so far, in order
+    log: Vec<LoggedCommand>,
+    history: History,
+    undo_policy: UndoPolicy,
+    /// Shared with the snapshots of the game, since it never changes
+    ruleset: Arc<Ruleset>,
+    status: GameStatus,
+    /// Whether each player has had a piece on the board, so losing them all eliminates them
+    has_fielded: [bool; 2],
+    /// The consecutive turns each player has ended occupying every objective tile
+    objective_turns: [u32; 2],
+    /// The id given to the next piece to enter the game
+    next_piece_id: u32,
 }
 
 impl Game {
+    /// Creates a game on an empty board with the [default rules](Ruleset::default)
+    ///
+    /// The game starts in the command phase of turn 1, after player 1 has collected their
+    /// income.
     #[must_use]
-    pub const fn new() -> Self {
-        Self {
+    pub fn new() -> Self {
+        let ruleset = Ruleset::default();
+        let mut game = Self {
             board: Board::new(),
             current_player: Player::P1,
-            money: [0, 0],
-        }
+            phase: Phase::Command,
+            turn: 1,
+            money: [ruleset.starting_money; 2],
+            pending_battle: None,
+            log: vec![],
+            history: History::new(),
+            undo_policy: UndoPolicy::CASUAL,
+            ruleset: Arc::new(ruleset),
+            status: GameStatus::Ongoing,
+            has_fielded: [false, false],
+            objective_turns: [0, 0],
+            next_piece_id: 0,
+        };
+
+        game.collect_income();
+        game
     }
 
     #[must_use]
@@ -36,164 +95,418 @@ impl Game {
         &self.board
     }
 
+    /// Returns the rules the game is played with
+    #[must_use]
+    pub fn ruleset(&self) -> &Ruleset {
+        &self.ruleset
+    }
+
+    /// Returns the definitions of the piece types that can be used in the game
+    #[must_use]
+    pub fn units(&self) -> &Units {
+        &self.ruleset.units
+    }
+
     #[must_use]
     pub const fn current_player(&self) -> Player {
         self.current_player
     }
+
+    /// Returns the player expected to issue the next command, which is the defending player
+    /// while a battle is pending
+    #[must_use]
+    pub fn acting_player(&self) -> Player {
+        if self.pending_battle.is_some() {
+            -self.current_player
+        } else {
+            self.current_player
+        }
+    }
+
+    /// Returns the battle awaiting the response of the defending player, if any
+    #[must_use]
+    pub const fn pending_battle(&self) -> Option<&PendingBattle> {
+        self.pending_battle.as_ref()
+    }
+
+    /// Returns the current phase, which is always [`Phase::Command`] between commands since the
+    /// other phases run while the turn ends
+    #[must_use]
+    pub const fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    #[must_use]
+    pub const fn turn(&self) -> u32 {
+        self.turn
+    }
+
+    /// Returns every command accepted so far, in the order they were applied
+    #[must_use]
+    pub fn log(&self) -> &[LoggedCommand] {
+        &self.log
+    }
+
+    #[must_use]
+    pub fn money(&self, player: Player) -> u8 {
+        self.money[player]
+    }
+
+    /// Returns the money `player` earns at the start of their turn
+    #[must_use]
+    pub fn income(&self, player: Player) -> u8 {
+        self.board
+            .tiles()
+            .filter(|tile| tile.controller() == Some(player))
+            .fold(self.ruleset.base_income, |income, tile| {
+                income.saturating_add(tile.income_bonus(&self.ruleset))
+            })
+    }
+
+    /// Returns the piece with `id`, if it is still in the game
+    #[must_use]
+    pub fn piece(&self, id: PieceId) -> Option<Piece> {
+        self.locate(id).map(|coord| self.board[coord])
+    }
+
+    /// Returns the coord of the piece with `id`, if it is still in the game
+    #[must_use]
+    pub fn locate(&self, id: PieceId) -> Option<PieceCoord> {
+        self.board
+            .piece_coords()
+            .filter_map(|coord| coord.into_piece_coord(&self.board))
+            .find(|coord| self.board[*coord].id() == id)
+    }
+
+    /// Returns every tile supplied to `player`
+    #[must_use]
+    pub fn supplied_tiles(&self, player: Player) -> HashSet<TileCoord> {
+        supply::supplied_coords(&self.board, &self.ruleset, player)
+            .into_iter()
+            .filter_map(|coord| coord.into_tile_coord(&self.board))
+            .collect()
+    }
+
+    /// Returns whether the piece at `coord` is supplied by its owner
+    #[must_use]
+    pub fn is_supplied(&self, coord: PieceCoord) -> bool {
+        self.piece_at(coord).is_ok_and(|piece| {
+            supply::supplied_coords(&self.board, &self.ruleset, piece.owner())
+                .contains(&coord.as_coord())
+        })
+    }
 }
 
 impl Game {
-    pub fn do_command(&mut self, command: &Command) {
-        match command {
-            Command::Move { from, to } => self.do_move(*from, *to),
-            Command::Recruit { piece_type, coord } => self.do_recruit(*piece_type, *coord),
+    /// Applies `command` as the acting player, recording it in the command log and checking
+    /// whether the game has ended
+    ///
+    /// Returns the report of the battle resolved by a [`Command::Defend`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason the command is illegal; the game is left unchanged.
+    pub fn do_command(&mut self, command: &Command) -> Result<Option<BattleReport>, CommandError> {
+        self.check_command(command)?;
+
+        let turn = self.turn;
+        let player = self.acting_player();
+        let before = self.undo_policy.allows(command).then(|| self.snapshot());
+
+        let report = match command {
+            Command::Move { from, to } => {
+                self.apply_move(*from, *to);
+                None
+            }
+            Command::Recruit { piece_type, coord } => {
+                self.apply_recruit(*piece_type, *coord);
+                None
+            }
             Command::Battle {
                 target,
-                target_is_defending,
                 initiator,
                 attack_supporters,
+            } => {
+                self.apply_battle(*target, *initiator, attack_supporters);
+                None
+            }
+            Command::Defend {
+                target_is_defending,
                 defence_supporters,
-            } => self.do_battle(
-                *target,
-                *target_is_defending,
-                *initiator,
+            } => Some(self.apply_defend(*target_is_defending, defence_supporters)),
+            Command::EndTurn => {
+                self.apply_end_turn();
+                None
+            }
+        };
+
+        self.update_status();
+
+        self.log.push(LoggedCommand {
+            turn,
+            player,
+            command: command.clone(),
+        });
+        self.record(before);
+
+        Ok(report)
+    }
+
+    /// Checks whether the acting player may issue `command`
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule the command breaks.
+    pub fn check_command(&self, command: &Command) -> Result<(), CommandError> {
+        match command {
+            Command::Move { from, to } => self.check_move(*from, *to),
+            Command::Recruit { piece_type, coord } => self.check_recruit(*piece_type, *coord),
+            Command::Battle {
+                target,
+                initiator,
                 attack_supporters,
+            } => self.check_battle(*target, *initiator, attack_supporters),
+            Command::Defend {
+                target_is_defending,
                 defence_supporters,
-            ),
-            Command::EndTurn => self.do_end_turn(),
+            } => self.check_defend(*target_is_defending, defence_supporters),
+            Command::EndTurn => self.check_can_command(),
         }
     }
 
-    pub fn do_move(&mut self, from: PieceCoord, to: TileCoord) {
-        if !self.can_do_move(from, to) {
-            // TODO: Error handling
-            return;
-        }
+    /// Moves the piece at `from` to `to`
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::check_move`].
+    pub fn do_move(&mut self, from: PieceCoord, to: TileCoord) -> Result<(), CommandError> {
+        self.do_command(&Command::Move { from, to }).map(drop)
+    }
 
+    /// Moves the piece at `from` to `to`, which must have been checked
+    fn apply_move(&mut self, from: PieceCoord, to: TileCoord) {
         let mut piece = self.board[from];
 
-        piece.exhausted = true;
-        self.board[to].piece_option = Some(piece);
+        piece.actions.moved = true;
         self.board[from.as_coord()].piece_option = None;
+        self.place_piece(piece, to);
     }
 
     #[must_use]
     pub fn can_do_move(&self, from: PieceCoord, to: TileCoord) -> bool {
-        self.can_move(from, to, self.current_player)
+        self.check_move(from, to).is_ok()
     }
 
-    #[must_use]
-    fn can_move(&self, from: PieceCoord, to: TileCoord, player: Player) -> bool {
-        if self.board[to].piece_option.is_some() {
-            // TODO: Error handling
-            return false;
+    /// Checks whether the current player may move the piece at `from` to `to`
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule the move breaks.
+    pub fn check_move(&self, from: PieceCoord, to: TileCoord) -> Result<(), CommandError> {
+        self.check_can_command()?;
+        self.check_move_for(from, to, self.current_player)
+    }
+
+    fn check_move_for(
+        &self,
+        from: PieceCoord,
+        to: TileCoord,
+        player: Player,
+    ) -> Result<(), CommandError> {
+        if self.tile_at(to)?.piece_option.is_some() {
+            return Err(CommandError::TileOccupied(to));
         }
 
-        let piece = self.board[from];
+        let piece = self.piece_at(from)?;
+        let unit = self.unit(piece);
 
-        if piece.exhausted {
-            // TODO: Error handling
-            return false;
+        if piece.is_exhausted(unit) {
+            return Err(CommandError::PieceExhausted(from));
         }
 
         if piece.owner() != player {
-            return false;
+            return Err(CommandError::WrongOwner {
+                coord: from,
+                owner: piece.owner(),
+            });
+        }
+
+        if !piece.can_move(unit) {
+            return Err(CommandError::AlreadyActed(from));
         }
 
-        if from.distance(to) > piece.speed() {
-            // TODO: Error handling
-            return false;
+        let distance = from.distance(to);
+
+        if distance > u16::from(unit.speed) {
+            return Err(CommandError::OutOfSpeed {
+                distance,
+                speed: unit.speed,
+            });
         }
 
-        // TODO: check pathing
+        if !self.reach(from.as_coord(), piece).contains(to.as_coord()) {
+            return Err(CommandError::NoPath(to));
+        }
+
+        Ok(())
+    }
+
+    /// Returns every empty tile the piece at `from` could move to, ignoring exhaustion, in coord
+    /// order
+    #[must_use]
+    pub fn reachable_tiles(&self, from: PieceCoord) -> Vec<TileCoord> {
+        let Ok(piece) = self.piece_at(from) else {
+            return vec![];
+        };
 
-        true
+        self.reach(from.as_coord(), piece)
+            .destinations(&self.board)
+            .filter_map(|coord| coord.into_tile_coord(&self.board))
+            .collect()
     }
 
-    pub fn do_recruit(&mut self, piece_type: PieceType, coord: TileCoord) {
-        if !self.can_do_recruit(piece_type, coord) {
-            // TODO: Error handling
-            return;
+    /// Returns the cheapest unblocked path from `from` to `to` within the piece's speed,
+    /// including both ends, or `None` unless `to` is one of its
+    /// [reachable tiles](Self::reachable_tiles)
+    #[must_use]
+    pub fn path(&self, from: PieceCoord, to: TileCoord) -> Option<Vec<TileCoord>> {
+        let piece = self.piece_at(from).ok()?;
+        let reach = self.reach(from.as_coord(), piece);
+
+        if !reach.is_destination(&self.board, to.as_coord()) {
+            return None;
         }
 
-        self.money[self.current_player] -= piece_type.cost();
-        self.board[coord].piece_option = Some(Piece::new(piece_type, self.current_player));
+        let path = reach.path_to(to.as_coord())?;
+
+        pathing::into_tile_coords(path, &self.board)
+    }
+
+    /// Recruits a new piece of `piece_type` at `coord`
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::check_recruit`].
+    pub fn do_recruit(
+        &mut self,
+        piece_type: PieceType,
+        coord: TileCoord,
+    ) -> Result<(), CommandError> {
+        self.do_command(&Command::Recruit { piece_type, coord })
+            .map(drop)
+    }
+
+    /// Recruits a piece of `piece_type` at `coord`, which must have been checked
+    fn apply_recruit(&mut self, piece_type: PieceType, coord: TileCoord) {
+        self.money[self.current_player] -= self.ruleset.units[piece_type].cost;
+        let mut piece = Piece::new(piece_type, self.current_player).with_id(self.take_piece_id());
+
+        piece.actions.recruited = self.ruleset.recruits_exhausted;
+        self.place_piece(piece, coord);
     }
 
     #[must_use]
     pub fn can_do_recruit(&self, piece_type: PieceType, coord: TileCoord) -> bool {
-        let tile = self.board[coord];
+        self.check_recruit(piece_type, coord).is_ok()
+    }
+
+    /// Checks whether the current player may recruit a piece of `piece_type` at `coord`
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule the recruitment breaks.
+    pub fn check_recruit(
+        &self,
+        piece_type: PieceType,
+        coord: TileCoord,
+    ) -> Result<(), CommandError> {
+        self.check_can_command()?;
+
+        let tile = self.tile_at(coord)?;
 
         if tile.piece_option.is_some() {
-            // TODO: Error handling
-            return false;
+            return Err(CommandError::TileOccupied(coord));
         }
 
         if !tile.can_recruit(self.current_player) {
-            // TODO: Error handling
-            return false;
+            return Err(CommandError::NotRecruitmentTile(coord));
         }
 
-        if self.money[self.current_player] < piece_type.cost() {
-            // TODO: Error handling
-            return false;
+        let unit = self
+            .ruleset
+            .units
+            .get(piece_type)
+            .ok_or(CommandError::UnknownPieceType(piece_type))?;
+        let money = self.money[self.current_player];
+
+        if money < unit.cost {
+            return Err(CommandError::InsufficientMoney {
+                cost: unit.cost,
+                money,
+            });
         }
 
-        true
+        Ok(())
     }
 
+    /// Declares a battle against the piece at `target`, moving and exhausting the attacking
+    /// pieces and waiting for the defending player to respond with [`Self::do_defend`]
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::check_battle`].
     pub fn do_battle(
         &mut self,
         target: PieceCoord,
-        target_is_defending: bool,
         initiator: BattleActor,
         attack_supporters: &[BattleActor],
-        defence_supporters: &[BattleActor],
-    ) {
-        if !self.can_do_battle(
+    ) -> Result<(), CommandError> {
+        self.do_command(&Command::Battle {
             target,
-            target_is_defending,
             initiator,
-            attack_supporters,
-            defence_supporters,
-        ) {
-            // TODO: Error handling
-            return;
-        }
+            attack_supporters: attack_supporters.to_vec(),
+        })
+        .map(drop)
+    }
 
-        let attack_power = attack_supporters
-            .iter()
-            .fold(self.resolve_actor(initiator), |power, actor| {
-                power + self.resolve_actor(*actor)
-            });
-        let defence_power = defence_supporters.iter().fold(
-            if target_is_defending {
-                self.resolve_actor(BattleActor::Static { coord: target })
-            } else {
-                0
-            },
-            |power, actor| power + self.resolve_actor(*actor),
-        );
-
-        if attack_power > defence_power {
-            self.board[target.as_coord()].piece_option = None;
+    /// Declares the described battle, which must have been checked
+    pub(crate) fn apply_battle(
+        &mut self,
+        target: PieceCoord,
+        initiator: BattleActor,
+        attack_supporters: &[BattleActor],
+    ) {
+        self.commit_actor(initiator, false);
+
+        for actor in attack_supporters {
+            self.commit_actor(*actor, true);
         }
+
+        self.pending_battle = Some(PendingBattle {
+            target,
+            initiator,
+            attack_supporters: attack_supporters.to_vec(),
+        });
     }
 
-    fn resolve_actor(&mut self, battle_actor: BattleActor) -> u8 {
+    /// Moves the piece acting as `battle_actor` and records that it attacked or supported
+    fn commit_actor(&mut self, battle_actor: BattleActor, is_supporting: bool) {
+        let battle_action = if is_supporting {
+            BattleAction::Supported
+        } else {
+            BattleAction::Attacked
+        };
+
         match battle_actor {
             BattleActor::Static { coord } => {
-                let mut piece = self.board[coord];
-                piece.exhausted = true;
-                self.board[coord.as_coord()].piece_option = Some(piece);
-                piece.power()
+                self.board[coord].actions.battle = Some(battle_action);
             }
             BattleActor::Moving { from, to } => {
                 let mut piece = self.board[from];
-                piece.exhausted = true;
-                self.board[to].piece_option = Some(piece);
+                piece.actions.moved = true;
+                piece.actions.battle = Some(battle_action);
                 self.board[from.as_coord()].piece_option = None;
-                piece.power()
+                self.place_piece(piece, to);
             }
         }
     }
@@ -202,45 +515,158 @@ impl Game {
     pub fn can_do_battle(
         &self,
         target: PieceCoord,
-        target_is_defending: bool,
         initiator: BattleActor,
         attack_supporters: &[BattleActor],
-        defence_supporters: &[BattleActor],
     ) -> bool {
-        let target_piece = self.board[target];
+        self.check_battle(target, initiator, attack_supporters)
+            .is_ok()
+    }
+
+    /// Checks whether the current player may declare the described battle
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule the battle breaks; failing participants are reported as
+    /// [`CommandError::InvalidActor`].
+    pub fn check_battle(
+        &self,
+        target: PieceCoord,
+        initiator: BattleActor,
+        attack_supporters: &[BattleActor],
+    ) -> Result<(), CommandError> {
+        self.check_can_command()?;
+
+        if self.piece_at(target)?.owner() == self.current_player {
+            return Err(CommandError::FriendlyTarget(target));
+        }
+
+        Self::check_distinct_actors(target, std::iter::once(&initiator).chain(attack_supporters))?;
+
+        self.validate_actor(
+            initiator,
+            target,
+            self.current_player,
+            BattleRole::Initiator,
+        )?;
+
+        for (index, actor) in attack_supporters.iter().enumerate() {
+            self.validate_actor(
+                *actor,
+                target,
+                self.current_player,
+                BattleRole::AttackSupporter(index),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Responds to the pending battle as the defending player and resolves it
+    ///
+    /// The target gains the defence bonus of its tile if it defends.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::check_defend`].
+    pub fn do_defend(
+        &mut self,
+        target_is_defending: bool,
+        defence_supporters: &[BattleActor],
+    ) -> Result<BattleReport, CommandError> {
+        self.do_command(&Command::Defend {
+            target_is_defending,
+            defence_supporters: defence_supporters.to_vec(),
+        })
+        .and_then(|report| report.ok_or(CommandError::NoPendingBattle))
+    }
+
+    /// Resolves the pending battle with the given response, which must have been checked
+    fn apply_defend(
+        &mut self,
+        target_is_defending: bool,
+        defence_supporters: &[BattleActor],
+    ) -> BattleReport {
+        let pending = self
+            .pending_battle
+            .take()
+            .expect("a checked response has a pending battle");
+        let report = self.evaluate_battle(&pending, target_is_defending, defence_supporters);
+
+        if target_is_defending {
+            self.commit_actor(
+                BattleActor::Static {
+                    coord: pending.target,
+                },
+                false,
+            );
+        }
 
-        if target_piece.owner() == self.current_player {
-            // TODO: Error handling
-            return false;
+        for actor in defence_supporters {
+            self.commit_actor(*actor, true);
         }
 
-        if target_is_defending && !target_piece.can_defend() {
-            // TODO: Error handling
-            return false;
+        if report.outcome == BattleOutcome::AttackerWins {
+            self.board[pending.target.as_coord()].piece_option = None;
         }
 
-        if !self.validate_actor(initiator, target, self.current_player, true) {
-            // TODO: Error handling
-            return false;
+        report
+    }
+
+    #[must_use]
+    pub fn can_do_defend(
+        &self,
+        target_is_defending: bool,
+        defence_supporters: &[BattleActor],
+    ) -> bool {
+        self.check_defend(target_is_defending, defence_supporters)
+            .is_ok()
+    }
+
+    /// Checks whether the defending player may respond to the pending battle as described
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule the response breaks; failing participants are reported as
+    /// [`CommandError::InvalidActor`].
+    pub fn check_defend(
+        &self,
+        target_is_defending: bool,
+        defence_supporters: &[BattleActor],
+    ) -> Result<(), CommandError> {
+        if self.status.is_over() {
+            return Err(CommandError::GameOver(self.status));
         }
 
-        if !attack_supporters
-            .iter()
-            .all(|actor| self.validate_actor(*actor, target, self.current_player, true))
-        {
-            // TODO: Error handling
-            return false;
+        let Some(pending) = &self.pending_battle else {
+            return Err(CommandError::NoPendingBattle);
+        };
+        let target = pending.target;
+        let target_piece = self.board[target];
+
+        Self::check_distinct_actors(target, defence_supporters)?;
+
+        if target_is_defending {
+            let unit = self.unit(target_piece);
+
+            if !unit.can_defend() {
+                return Err(CommandError::CannotDefend(target));
+            }
+
+            if target_piece.is_exhausted(unit) {
+                return Err(CommandError::PieceExhausted(target));
+            }
         }
 
-        if !defence_supporters
-            .iter()
-            .all(|actor| self.validate_actor(*actor, target, -self.current_player, false))
-        {
-            // TODO: Error handling
-            return false;
+        for (index, actor) in defence_supporters.iter().enumerate() {
+            self.validate_actor(
+                *actor,
+                target,
+                target_piece.owner(),
+                BattleRole::DefenceSupporter(index),
+            )?;
         }
 
-        true
+        Ok(())
     }
 
     fn validate_actor(
@@ -248,47 +674,235 @@ impl Game {
         battle_actor: BattleActor,
         target: PieceCoord,
         player: Player,
-        is_attacking: bool,
-    ) -> bool {
-        let (piece, end_coord, is_moving) = match battle_actor {
+        role: BattleRole,
+    ) -> Result<(), CommandError> {
+        let invalid = |reason| CommandError::InvalidActor {
+            role,
+            reason: Box::new(reason),
+        };
+
+        let (coord, end_coord, is_moving) = match battle_actor {
             BattleActor::Static { coord } => {
-                let piece = self.board[coord];
+                let piece = self.piece_at(coord).map_err(invalid)?;
 
-                if piece.exhausted {
-                    // TODO: Error handling
-                    return false;
+                if piece.is_exhausted(self.unit(piece)) {
+                    return Err(invalid(CommandError::PieceExhausted(coord)));
                 }
 
                 if piece.owner() != player {
-                    // TODO: Error handling
-                    return false;
+                    return Err(invalid(CommandError::WrongOwner {
+                        coord,
+                        owner: piece.owner(),
+                    }));
                 }
 
-                (piece, coord.as_coord(), false)
+                (coord, coord.as_coord(), false)
             }
             BattleActor::Moving { from, to } => {
-                if !self.can_move(from, to, player) {
-                    // TODO: Error handling
-                    return false;
-                }
+                self.check_move_for(from, to, player).map_err(invalid)?;
 
-                (self.board[from], to.as_coord(), true)
+                (from, to.as_coord(), true)
             }
         };
+        let unit = self.unit(self.board[coord]);
+        let is_attacking = !matches!(role, BattleRole::DefenceSupporter(_));
+
+        if role == BattleRole::Initiator && !unit.can_initiate() {
+            return Err(invalid(CommandError::CannotInitiate(coord)));
+        }
+
+        // Initiators only need the ability to move in, if they do
+        let needs_support = role != BattleRole::Initiator || is_moving;
+
+        if needs_support && !unit.can_support(is_attacking, is_moving) {
+            return Err(invalid(CommandError::CannotSupport(coord)));
+        }
+
+        let distance = end_coord.distance(target);
+
+        if !unit.in_range(distance) {
+            return Err(invalid(CommandError::OutOfRange {
+                distance,
+                range: unit.range.clone(),
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that no piece takes part in a battle against `target` more than once, counting
+    /// the target itself, and that no two pieces move to the same tile
+    fn check_distinct_actors<'a>(
+        target: PieceCoord,
+        battle_actors: impl IntoIterator<Item = &'a BattleActor>,
+    ) -> Result<(), CommandError> {
+        let mut coords = HashSet::from([target]);
+        let mut destinations = HashSet::new();
+
+        for battle_actor in battle_actors {
+            let (coord, destination) = match *battle_actor {
+                BattleActor::Static { coord } => (coord, None),
+                BattleActor::Moving { from, to } => (from, Some(to)),
+            };
+
+            if !coords.insert(coord) {
+                return Err(CommandError::RepeatedActor(coord));
+            }
+
+            if let Some(to) = destination
+                && !destinations.insert(to)
+            {
+                return Err(CommandError::SharedDestination(to));
+            }
+        }
+
+        Ok(())
+    }
 
-        piece.range().contains(&end_coord.distance(target))
-            && piece.can_support(is_attacking, is_moving)
+    /// Ends the turn of the current player, running the resupply phase and then the income
+    /// phase of the next player
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::check_can_command`].
+    pub fn do_end_turn(&mut self) -> Result<(), CommandError> {
+        self.do_command(&Command::EndTurn).map(drop)
     }
 
-    pub fn do_end_turn(&mut self) {
-        // Resupply phase
-        // TODO
+    /// Ends the turn of the current player, which must have been checked
+    fn apply_end_turn(&mut self) {
+        self.phase = self.phase.next();
+        self.resupply();
+        self.hold_objectives();
 
-        // Turn change
         self.current_player = -self.current_player;
+        self.turn += 1;
+        self.phase = self.phase.next();
+        self.refresh();
+        self.collect_income();
+
+        self.phase = self.phase.next();
+    }
+
+    /// Checks that the current player may issue commands
+    ///
+    /// # Errors
+    ///
+    /// Fails if the game is over or a battle is awaiting the response of the defending player.
+    pub const fn check_can_command(&self) -> Result<(), CommandError> {
+        if self.status.is_over() {
+            return Err(CommandError::GameOver(self.status));
+        }
+
+        if self.pending_battle.is_some() {
+            return Err(CommandError::BattlePending);
+        }
+
+        Ok(())
+    }
+}
+
+impl Game {
+    /// Returns the tile at `coord`, which may have been created for another board
+    fn tile_at(&self, coord: TileCoord) -> Result<Tile, CommandError> {
+        self.board
+            .get(coord.as_coord())
+            .ok_or(CommandError::NoTile(coord))
+    }
+
+    /// Returns the piece at `coord`, which may be out of date or created for another board
+    fn piece_at(&self, coord: PieceCoord) -> Result<Piece, CommandError> {
+        self.board
+            .get(coord.as_coord())
+            .and_then(|tile| tile.piece_option)
+            .ok_or(CommandError::NoPiece(coord))
+    }
+
+    /// Returns the definition of the type of `piece`, which every piece in the game has
+    fn unit(&self, piece: Piece) -> &UnitDef {
+        &self.ruleset.units[piece.kind()]
+    }
+
+    /// Searches the tiles `piece` at `coord` could move through this turn
+    fn reach(&self, coord: Coord, piece: Piece) -> Reach {
+        Reach::search(
+            &self.board,
+            &self.ruleset,
+            self.unit(piece),
+            coord,
+            piece.owner(),
+        )
+    }
+
+    /// Returns a new piece id, never handed out before in this game
+    const fn take_piece_id(&mut self) -> PieceId {
+        let id = PieceId(self.next_piece_id);
+
+        self.next_piece_id += 1;
+        id
+    }
+
+    /// Gives every piece on the board a new id, in board order
+    pub(crate) fn assign_piece_ids(&mut self) {
+        let coords: Vec<_> = self.board.piece_coords().collect();
+
+        for coord in coords {
+            let id = self.take_piece_id();
+
+            if let Some(piece) = &mut self.board[coord].piece_option {
+                *piece = piece.with_id(id);
+            }
+        }
+    }
+
+    /// Puts `piece` on the tile at `coord`, capturing the tile for its owner
+    fn place_piece(&mut self, piece: Piece, coord: TileCoord) {
+        let tile = &mut self.board[coord];
+
+        tile.piece_option = Some(piece);
+        tile.capture(piece.owner());
+    }
+
+    /// Readies every piece of the current player for a new turn
+    fn refresh(&mut self) {
+        let coords: Vec<_> = self.board.piece_coords().collect();
+
+        for coord in coords {
+            if let Some(piece) = &mut self.board[coord].piece_option
+                && piece.owner() == self.current_player
+            {
+                piece.actions = Actions::READY;
+            }
+        }
+    }
+
+    /// Gives the current player their income
+    pub(crate) fn collect_income(&mut self) {
+        let income = self.income(self.current_player);
 
-        // Income phase
-        // TODO
+        self.money[self.current_player] = self.money[self.current_player].saturating_add(income);
+    }
+
+    /// Disbands every unsupplied piece of the current player that cannot survive without supplies
+    fn resupply(&mut self) {
+        let supplied = supply::supplied_coords(&self.board, &self.ruleset, self.current_player);
+        let disbanded: Vec<_> = self
+            .board
+            .piece_coords()
+            .filter(|coord| {
+                let piece = self.board[*coord]
+                    .piece_option
+                    .expect("piece_coords only yields coords of pieces");
+
+                piece.owner() == self.current_player
+                    && self.unit(piece).disbands_unsupplied()
+                    && !supplied.contains(coord)
+            })
+            .collect();
+
+        for coord in disbanded {
+            self.board[coord].piece_option = None;
+        }
     }
 }
 
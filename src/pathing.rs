@@ -0,0 +1,113 @@
+use crate::{
+    board::Board,
+    coordinates::{Coord, TileCoord},
+    player::Player,
+    ruleset::Ruleset,
+    unit::UnitDef,
+};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap, HashMap},
+};
+
+/// The result of a cheapest first search over the tiles a piece could travel through
+#[derive(Clone, Debug)]
+pub struct Reach {
+    origin: Coord,
+    /// Maps every visited coord to the coord it is most cheaply reached from, ordered so that
+    /// results come out the same way every time
+    parents: BTreeMap<Coord, Coord>,
+}
+
+impl Reach {
+    /// Searches outwards from `origin` as a piece of `unit` owned by `player`, spending at most
+    /// the speed of the unit in movement costs
+    ///
+    /// Missing tiles, tiles the unit cannot enter and tiles holding enemy pieces cannot be
+    /// entered, while friendly pieces can be passed through.
+    pub fn search(
+        board: &Board,
+        ruleset: &Ruleset,
+        unit: &UnitDef,
+        origin: Coord,
+        player: Player,
+    ) -> Self {
+        let mut parents = BTreeMap::from([(origin, origin)]);
+        let mut costs = HashMap::from([(origin, 0)]);
+        let mut queue = BinaryHeap::from([Reverse((0, origin))]);
+
+        while let Some(Reverse((cost, coord))) = queue.pop() {
+            if costs.get(&coord).is_some_and(|best| *best < cost) {
+                continue;
+            }
+
+            for neighbour in board.coord_neighbours(coord).unwrap_or_default() {
+                let tile = board[neighbour];
+
+                if tile
+                    .piece_option
+                    .is_some_and(|piece| piece.owner() != player)
+                {
+                    continue;
+                }
+
+                let Some(move_cost) = tile.move_cost(ruleset, unit) else {
+                    continue;
+                };
+                let total = cost + u16::from(move_cost);
+
+                if total > u16::from(unit.speed)
+                    || costs.get(&neighbour).is_some_and(|best| *best <= total)
+                {
+                    continue;
+                }
+
+                costs.insert(neighbour, total);
+                parents.insert(neighbour, coord);
+                queue.push(Reverse((total, neighbour)));
+            }
+        }
+
+        Self { origin, parents }
+    }
+
+    /// Returns the coords of every empty tile the piece can end its move on, in coord order
+    pub fn destinations<'a>(&'a self, board: &'a Board) -> impl Iterator<Item = Coord> + 'a {
+        self.parents
+            .keys()
+            .copied()
+            .filter(move |coord| self.is_destination(board, *coord))
+    }
+
+    /// Whether the piece can end its move on `coord`, an empty tile other than its own
+    #[must_use]
+    pub fn is_destination(&self, board: &Board, coord: Coord) -> bool {
+        coord != self.origin && self.contains(coord) && board[coord].piece_option.is_none()
+    }
+
+    #[must_use]
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.parents.contains_key(&coord)
+    }
+
+    /// Returns the cheapest path from the origin to `target`, including both ends
+    pub fn path_to(&self, target: Coord) -> Option<Vec<Coord>> {
+        let mut coord = target;
+        let mut path = vec![coord];
+
+        while coord != self.origin {
+            coord = *self.parents.get(&coord)?;
+            path.push(coord);
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Converts a path of coords that are known to index tiles into `TileCoord`s
+pub fn into_tile_coords(path: Vec<Coord>, board: &Board) -> Option<Vec<TileCoord>> {
+    path.into_iter()
+        .map(|coord| coord.into_tile_coord(board))
+        .collect()
+}
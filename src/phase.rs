@@ -0,0 +1,25 @@
+/// A phase of a player's turn, in the order they occur
+///
+/// Only the command phase waits for the players; ending the turn runs the resupply phase and
+/// then the income phase of the next player.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// The current player gains their income
+    Income,
+    /// The current player issues commands
+    Command,
+    /// Unsupplied pieces of the current player are disbanded
+    Resupply,
+}
+
+impl Phase {
+    /// Returns the phase that follows this one, wrapping around to the next turn
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Income => Self::Command,
+            Self::Command => Self::Resupply,
+            Self::Resupply => Self::Income,
+        }
+    }
+}
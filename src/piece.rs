@@ -1,23 +1,41 @@
-use crate::player::Player;
-use std::ops::Range;
+use crate::{player::Player, unit::UnitDef};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Piece {
+    id: PieceId,
     kind: PieceType,
-    pub exhausted: bool,
+    pub actions: Actions,
     owner: Player,
 }
 
 impl Piece {
+    /// Creates a freshly recruited piece, which cannot act until its owner's next turn
+    ///
+    /// The piece is given its id once it is placed in a [`Game`](crate::Game).
     #[must_use]
     pub const fn new(kind: PieceType, owner: Player) -> Self {
         Self {
+            id: PieceId(0),
             kind,
-            exhausted: true,
+            actions: Actions {
+                recruited: true,
+                ..Actions::READY
+            },
             owner,
         }
     }
 
+    /// Returns the id of the piece, which stays the same for as long as it is in a game
+    #[must_use]
+    pub const fn id(self) -> PieceId {
+        self.id
+    }
+
+    pub(crate) const fn with_id(mut self, id: PieceId) -> Self {
+        self.id = id;
+        self
+    }
+
     #[must_use]
     pub const fn kind(self) -> PieceType {
         self.kind
@@ -28,103 +46,135 @@ impl Piece {
         self.owner
     }
 
+    /// Returns whether the piece can take no further part in this turn
+    ///
+    /// Any action exhausts a piece, except for supporting a battle with a unit that
+    /// [supports freely](UnitDef::supports_freely). `unit` is the definition of the piece's
+    /// type.
     #[must_use]
-    pub const fn speed(self) -> u8 {
-        self.kind.speed()
-    }
+    pub fn is_exhausted(self, unit: &UnitDef) -> bool {
+        let actions = self.actions;
 
-    #[must_use]
-    pub const fn power(self) -> u8 {
-        self.kind.power()
+        actions.recruited
+            || actions.moved
+            || match actions.battle {
+                Some(BattleAction::Attacked) => true,
+                Some(BattleAction::Supported) => !unit.supports_freely(),
+                None => false,
+            }
     }
 
+    /// Returns whether the piece can still move this turn, which it cannot once it has acted
     #[must_use]
-    pub const fn range(self) -> Range<u8> {
-        self.kind.range()
+    pub fn can_move(self, unit: &UnitDef) -> bool {
+        !self.is_exhausted(unit) && self.actions.battle.is_none()
     }
 
+    /// Returns the character representing the piece in the board text format, which is
+    /// uppercase for [`Player::P1`] and lowercase for [`Player::P2`]
     #[must_use]
-    pub const fn cost(self) -> u8 {
-        self.kind.cost()
-    }
+    pub const fn symbol(self) -> char {
+        let symbol = self.kind.symbol();
 
-    #[must_use]
-    pub const fn can_initiate(self) -> bool {
-        self.kind.can_initiate()
+        match self.owner {
+            Player::P1 => symbol,
+            Player::P2 => symbol.to_ascii_lowercase(),
+        }
     }
 
+    /// Creates a ready piece from its board text format character, which can be any ASCII
+    /// letter
+    ///
+    /// The piece is given its id once it is placed in a [`Game`](crate::Game).
     #[must_use]
-    pub const fn can_defend(self) -> bool {
-        self.kind.can_defend()
-    }
+    pub const fn from_symbol(symbol: char) -> Option<Self> {
+        let owner = if symbol.is_ascii_uppercase() {
+            Player::P1
+        } else {
+            Player::P2
+        };
+        let Some(kind) = PieceType::from_symbol(symbol.to_ascii_uppercase()) else {
+            return None;
+        };
 
-    #[must_use]
-    pub const fn can_support(self, is_attacking: bool, is_moving: bool) -> bool {
-        self.kind.can_support(is_attacking, is_moving)
+        Some(Self {
+            id: PieceId(0),
+            kind,
+            actions: Actions::READY,
+            owner,
+        })
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum PieceType {
-    Artillery,
-    Convoy,
-    Infantry,
-    Recon,
-}
+/// Identifies a piece within a game
+///
+/// Ids are assigned in the order pieces enter the game and are never reused.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PieceId(pub(crate) u32);
 
-impl PieceType {
+impl PieceId {
     #[must_use]
-    pub const fn speed(self) -> u8 {
-        match self {
-            Self::Artillery | Self::Infantry => 2,
-            Self::Convoy | Self::Recon => 3,
-        }
+    pub const fn get(self) -> u32 {
+        self.0
     }
+}
 
-    #[must_use]
-    pub const fn power(self) -> u8 {
-        match self {
-            Self::Artillery | Self::Infantry => 2,
-            Self::Convoy => 0,
-            Self::Recon => 1,
-        }
-    }
+/// What a piece has done since it was last readied
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Actions {
+    pub moved: bool,
+    /// The part the piece has taken in battles
+    pub battle: Option<BattleAction>,
+    /// Whether the piece was recruited this turn and cannot act until its owner's next turn
+    pub recruited: bool,
+}
 
-    #[must_use]
-    pub const fn range(self) -> Range<u8> {
-        match self {
-            Self::Artillery => 2..4,
-            Self::Convoy => 0..0,
-            Self::Infantry | Self::Recon => 1..2,
-        }
-    }
+impl Actions {
+    /// The state of a piece that has done nothing yet
+    pub const READY: Self = Self {
+        moved: false,
+        battle: None,
+        recruited: false,
+    };
+}
 
-    #[must_use]
-    pub const fn cost(self) -> u8 {
-        match self {
-            Self::Artillery | Self::Convoy => 3,
-            Self::Infantry => 2,
-            Self::Recon => 4,
-        }
-    }
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BattleAction {
+    /// The piece initiated a battle or defended itself
+    Attacked,
+    Supported,
+}
 
-    #[must_use]
-    pub const fn can_initiate(self) -> bool {
-        matches!(self, Self::Infantry | Self::Recon)
-    }
+/// Identifies a type of piece by the uppercase letter representing it in the board text format
+///
+/// What a type of piece can do is given by its [`UnitDef`] in the [`Units`](crate::unit::Units)
+/// of the game.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PieceType(char);
+
+impl PieceType {
+    /// The artillery of the default ruleset
+    pub const ARTILLERY: Self = Self('A');
+    /// The convoy of the default ruleset
+    pub const CONVOY: Self = Self('C');
+    /// The infantry of the default ruleset
+    pub const INFANTRY: Self = Self('I');
+    /// The recon of the default ruleset
+    pub const RECON: Self = Self('R');
 
+    /// Returns the uppercase character representing the piece type in the board text format
     #[must_use]
-    pub const fn can_defend(self) -> bool {
-        matches!(self, Self::Infantry | Self::Recon)
+    pub const fn symbol(self) -> char {
+        self.0
     }
 
+    /// Returns the piece type represented by `symbol`, which must be an uppercase ASCII letter
     #[must_use]
-    pub const fn can_support(self, is_attacking: bool, is_moving: bool) -> bool {
-        match self {
-            Self::Artillery => !is_moving,
-            Self::Convoy => false,
-            Self::Infantry => !is_moving || is_attacking,
-            Self::Recon => true,
+    pub const fn from_symbol(symbol: char) -> Option<Self> {
+        if symbol.is_ascii_uppercase() {
+            Some(Self(symbol))
+        } else {
+            None
         }
     }
 }
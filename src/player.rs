@@ -1,4 +1,7 @@
-use std::ops::{Index, IndexMut, Neg};
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut, Neg},
+};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Player {
@@ -17,6 +20,15 @@ impl Neg for Player {
     }
 }
 
+impl Display for Player {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::P1 => write!(f, "player 1"),
+            Self::P2 => write!(f, "player 2"),
+        }
+    }
+}
+
 impl<T> Index<Player> for [T; 2] {
     type Output = T;
 
@@ -0,0 +1,100 @@
+use crate::{Game, actions::Command, error::CommandError};
+
+/// Reproduces a game by re-running a list of commands from an initial setup
+///
+/// The cursor counts how many commands have been applied to [`Self::game`]. Stepping back
+/// re-runs the commands from the initial setup, relying on games being deterministic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Replay {
+    initial: Game,
+    commands: Vec<Command>,
+    cursor: usize,
+    game: Game,
+}
+
+impl Replay {
+    /// Creates a replay positioned before the first command
+    #[must_use]
+    pub fn new(initial: Game, commands: Vec<Command>) -> Self {
+        Self {
+            game: initial.clone(),
+            initial,
+            commands,
+            cursor: 0,
+        }
+    }
+
+    /// Returns the game after the commands before the cursor have been applied
+    #[must_use]
+    pub const fn game(&self) -> &Game {
+        &self.game
+    }
+
+    #[must_use]
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns whether every command has been applied
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.cursor == self.commands.len()
+    }
+
+    /// Applies the command at the cursor, returning `false` if there are no commands left
+    ///
+    /// # Errors
+    ///
+    /// Fails without moving the cursor if the game rejects the command.
+    pub fn step_forward(&mut self) -> Result<bool, CommandError> {
+        let Some(command) = self.commands.get(self.cursor) else {
+            return Ok(false);
+        };
+
+        self.game.do_command(command)?;
+        self.cursor += 1;
+
+        Ok(true)
+    }
+
+    /// Reverts the last applied command, returning `false` if none have been applied
+    ///
+    /// # Errors
+    ///
+    /// Fails if re-running the earlier commands does not reproduce the game, in which case the
+    /// cursor is left before the command that was rejected.
+    pub fn step_back(&mut self) -> Result<bool, CommandError> {
+        let Some(cursor) = self.cursor.checked_sub(1) else {
+            return Ok(false);
+        };
+
+        self.seek(cursor)?;
+
+        Ok(true)
+    }
+
+    /// Moves the cursor to `cursor`, clamped to the number of commands
+    ///
+    /// # Errors
+    ///
+    /// Fails if a command is rejected, leaving the cursor before it.
+    pub fn seek(&mut self, cursor: usize) -> Result<(), CommandError> {
+        let cursor = cursor.min(self.commands.len());
+
+        if cursor < self.cursor {
+            self.game = self.initial.clone();
+            self.cursor = 0;
+        }
+
+        while self.cursor < cursor {
+            self.step_forward()?;
+        }
+
+        Ok(())
+    }
+}
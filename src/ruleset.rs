@@ -0,0 +1,624 @@
+use crate::{
+    coordinates::{Coord, Coordinate},
+    piece::PieceType,
+    tile::{Terrain, TileType},
+    unit::{Ability, UnitDef, Units},
+    victory::{Objectives, Tiebreak, TurnLimit, VictoryConditions},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::Range,
+    str::FromStr,
+};
+
+/// The rules of the standard game in the ruleset text format, which also documents the format
+pub const DEFAULT_RULESET: &str = include_str!("../rulesets/default.txt");
+
+/// The value of optional keys that are not set
+const NONE: &str = "none";
+const RULES_KEYS: [&str; 5] = [
+    "base-income",
+    "starting-money",
+    "supply-radius",
+    "recruits-exhausted",
+    "defence-supporter-terrain",
+];
+const VICTORY_KEYS: [&str; 5] = [
+    "elimination",
+    "border-capture",
+    "money-target",
+    "objectives",
+    "turn-limit",
+];
+const TILE_KEYS: [&str; 3] = ["income", "defence", "move-cost"];
+const UNIT_KEYS: [&str; 7] = [
+    "symbol",
+    "speed",
+    "power",
+    "range",
+    "cost",
+    "abilities",
+    "move-costs",
+];
+
+/// The constants, victory conditions and units a game is played with
+///
+/// Rulesets are read from and written to a text format of `[<section>]` headers, each followed
+/// by `<key> = <value>` lines; see [`DEFAULT_RULESET`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ruleset {
+    /// The money each player earns per turn regardless of the tiles they control
+    pub base_income: u8,
+    /// The money each player starts with
+    pub starting_money: u8,
+    /// The distance in tiles over which a supply source provides supplies
+    pub supply_radius: u8,
+    /// Whether recruited pieces cannot act until their owner's next turn
+    pub recruits_exhausted: bool,
+    /// Whether defence supporters gain the defence bonus of the tile they support from, in
+    /// addition to the bonus the target gains from its own tile
+    pub defence_supporter_terrain: bool,
+    pub victory_conditions: VictoryConditions,
+    /// The stats of each terrain, in the order of [`Terrain::ALL`] and indexed by [`Terrain`]
+    pub tiles: [TileStats; 9],
+    pub units: Units,
+}
+
+impl Ruleset {
+    /// Returns the stats of tiles of type `kind`
+    #[must_use]
+    pub const fn tile_stats(&self, kind: TileType) -> TileStats {
+        self.terrain_stats(kind.terrain())
+    }
+
+    /// Returns the stats of tiles of `terrain`
+    #[must_use]
+    pub const fn terrain_stats(&self, terrain: Terrain) -> TileStats {
+        self.tiles[terrain.index()]
+    }
+
+    /// Checks that a game can be played with the ruleset
+    ///
+    /// # Errors
+    ///
+    /// At least one unit must be defined, movement costs must be above zero, the money target
+    /// must be above the starting money, objectives need tiles and to be held for at least one
+    /// turn, and the turn limit must allow at least one turn.
+    pub fn validate(&self) -> Result<(), RulesetError> {
+        let victory_conditions = &self.victory_conditions;
+
+        if self.units.is_empty() {
+            return Err(RulesetError::NoUnits);
+        }
+
+        if Terrain::ALL
+            .into_iter()
+            .map(|terrain| self.terrain_stats(terrain).move_cost)
+            .chain(
+                self.units
+                    .iter()
+                    .flat_map(|def| def.move_costs.values().copied()),
+            )
+            .any(|move_cost| move_cost == Some(0))
+        {
+            return Err(RulesetError::ZeroMoveCost);
+        }
+
+        if victory_conditions
+            .money_target
+            .is_some_and(|target| target <= self.starting_money)
+        {
+            return Err(RulesetError::MoneyTargetReached);
+        }
+
+        if victory_conditions
+            .objectives
+            .as_ref()
+            .is_some_and(|objectives| objectives.turns == 0 || objectives.tiles.is_empty())
+        {
+            return Err(RulesetError::InvalidObjectives);
+        }
+
+        if victory_conditions
+            .turn_limit
+            .is_some_and(|turn_limit| turn_limit.turns == 0)
+        {
+            return Err(RulesetError::ZeroTurnLimit);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the standard rules, as defined by [`DEFAULT_RULESET`]
+impl Default for Ruleset {
+    fn default() -> Self {
+        DEFAULT_RULESET
+            .parse()
+            .expect("the default ruleset is valid")
+    }
+}
+
+/// The bonuses a tile gives and what entering it costs
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TileStats {
+    /// The income bonus of the tile while controlled
+    pub income: u8,
+    /// The defence bonus of a piece defending itself on the tile
+    pub defence: u8,
+    /// The movement points a piece spends entering the tile, or `None` if the tile cannot be
+    /// entered, unless its unit says otherwise
+    pub move_cost: Option<u8>,
+}
+
+/// Formats the ruleset in the ruleset text format, without comments
+impl Display for Ruleset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let victory_conditions = &self.victory_conditions;
+
+        writeln!(f, "[rules]")?;
+        writeln!(f, "base-income = {}", self.base_income)?;
+        writeln!(f, "starting-money = {}", self.starting_money)?;
+        writeln!(f, "supply-radius = {}", self.supply_radius)?;
+        writeln!(f, "recruits-exhausted = {}", self.recruits_exhausted)?;
+        writeln!(
+            f,
+            "defence-supporter-terrain = {}",
+            self.defence_supporter_terrain
+        )?;
+
+        writeln!(f)?;
+        writeln!(f, "[victory]")?;
+        writeln!(f, "elimination = {}", victory_conditions.elimination)?;
+        writeln!(f, "border-capture = {}", victory_conditions.border_capture)?;
+        writeln!(
+            f,
+            "money-target = {}",
+            victory_conditions
+                .money_target
+                .map_or_else(|| NONE.to_owned(), |target| target.to_string())
+        )?;
+        write!(f, "objectives =")?;
+
+        match &victory_conditions.objectives {
+            Some(objectives) => {
+                write!(f, " {}", objectives.turns)?;
+
+                for coord in &objectives.tiles {
+                    write!(f, " {},{}", coord.rank(), coord.file())?;
+                }
+
+                writeln!(f)?;
+            }
+            None => writeln!(f, " {NONE}")?,
+        }
+
+        match victory_conditions.turn_limit {
+            Some(turn_limit) => writeln!(
+                f,
+                "turn-limit = {} {}",
+                turn_limit.turns,
+                tiebreak_name(turn_limit.tiebreak)
+            )?,
+            None => writeln!(f, "turn-limit = {NONE}")?,
+        }
+
+        for terrain in Terrain::ALL {
+            let stats = self.terrain_stats(terrain);
+
+            writeln!(f)?;
+            writeln!(f, "[{}]", tile_section_name(terrain))?;
+            writeln!(f, "income = {}", stats.income)?;
+            writeln!(f, "defence = {}", stats.defence)?;
+            writeln!(f, "move-cost = {}", move_cost_name(stats.move_cost))?;
+        }
+
+        for def in self.units.iter() {
+            writeln!(f)?;
+            writeln!(f, "[unit {}]", def.name)?;
+            writeln!(f, "symbol = {}", def.kind.symbol())?;
+            writeln!(f, "speed = {}", def.speed)?;
+            writeln!(f, "power = {}", def.power)?;
+            writeln!(f, "range = {}..{}", def.range.start, def.range.end)?;
+            writeln!(f, "cost = {}", def.cost)?;
+            write!(f, "abilities =")?;
+
+            for ability in &def.abilities {
+                write!(f, " {}", ability.name())?;
+            }
+
+            writeln!(f)?;
+            write!(f, "move-costs =")?;
+
+            for (terrain, move_cost) in &def.move_costs {
+                write!(f, " {}:{}", terrain.name(), move_cost_name(*move_cost))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses and validates a ruleset from the format written by its [`Display`] implementation
+///
+/// Every section and key must be given once, except that any number of units can be defined.
+impl FromStr for Ruleset {
+    type Err = RulesetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sections = sections(s)?;
+        let mut names = HashSet::new();
+
+        for section in &sections {
+            if section.unit_name().is_none() && !names.insert(section.name) {
+                return Err(RulesetError::DuplicateSection { line: section.line });
+            }
+        }
+
+        let find = |name: &str| {
+            sections
+                .iter()
+                .find(|section| section.name == name)
+                .ok_or_else(|| RulesetError::MissingSection(name.to_owned()))
+        };
+        let rules = find("rules")?;
+        let victory = find("victory")?;
+        let tile = |terrain: Terrain| {
+            let section = find(&tile_section_name(terrain))?;
+
+            section.check_keys(&TILE_KEYS)?;
+
+            Ok::<_, RulesetError>(TileStats {
+                income: section.parse("income")?,
+                defence: section.parse("defence")?,
+                move_cost: section.value("move-cost", |value| match value {
+                    NONE => Some(None),
+                    value => value.parse().ok().map(Some),
+                })?,
+            })
+        };
+
+        rules.check_keys(&RULES_KEYS)?;
+        victory.check_keys(&VICTORY_KEYS)?;
+
+        let units = sections
+            .iter()
+            .filter_map(|section| Some((section.unit_name()?, section)))
+            .map(|(name, section)| unit_def(name, section))
+            .collect::<Result<_, _>>()?;
+        let mut tiles = [TileStats::default(); 9];
+
+        for terrain in Terrain::ALL {
+            tiles[terrain] = tile(terrain)?;
+        }
+
+        let ruleset = Self {
+            base_income: rules.parse("base-income")?,
+            starting_money: rules.parse("starting-money")?,
+            supply_radius: rules.parse("supply-radius")?,
+            recruits_exhausted: rules.parse("recruits-exhausted")?,
+            defence_supporter_terrain: rules.parse("defence-supporter-terrain")?,
+            victory_conditions: victory_conditions(victory)?,
+            tiles,
+            units: Units::new(units)?,
+        };
+
+        ruleset.validate()?;
+
+        Ok(ruleset)
+    }
+}
+
+/// A `[<name>]` header and the `<key> = <value>` lines that follow it
+struct Section<'a> {
+    name: &'a str,
+    /// The one based number of the header line
+    line: usize,
+    /// Each key with its value and line number
+    entries: Vec<(&'a str, &'a str, usize)>,
+}
+
+impl<'a> Section<'a> {
+    /// Returns the name given to the unit if this is a unit section
+    fn unit_name(&self) -> Option<&'a str> {
+        self.name
+            .strip_prefix("unit ")
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Checks that every key is one of `keys` and appears at most once
+    fn check_keys(&self, keys: &[&str]) -> Result<(), RulesetError> {
+        let mut seen = HashSet::new();
+
+        for &(key, _, line) in &self.entries {
+            if !keys.contains(&key) {
+                return Err(RulesetError::UnknownKey {
+                    line,
+                    key: key.to_owned(),
+                });
+            }
+
+            if !seen.insert(key) {
+                return Err(RulesetError::DuplicateKey {
+                    line,
+                    key: key.to_owned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn value<T>(
+        &self,
+        key: &'static str,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, RulesetError> {
+        let &(_, value, line) = self
+            .entries
+            .iter()
+            .find(|(entry_key, ..)| *entry_key == key)
+            .ok_or_else(|| RulesetError::MissingKey {
+                section: self.name.to_owned(),
+                key,
+            })?;
+
+        parse(value).ok_or(RulesetError::InvalidValue { line, key })
+    }
+
+    fn parse<T: FromStr>(&self, key: &'static str) -> Result<T, RulesetError> {
+        self.value(key, |value| value.parse().ok())
+    }
+}
+
+/// Splits ruleset text into sections, skipping blank lines and comments
+fn sections(s: &str) -> Result<Vec<Section<'_>>, RulesetError> {
+    let mut sections: Vec<Section> = vec![];
+
+    for (index, line) in s.lines().enumerate() {
+        let number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            let section = Section {
+                name: name.trim(),
+                line: number,
+                entries: vec![],
+            };
+
+            if !matches!(section.name, "rules" | "victory")
+                && !Terrain::ALL
+                    .into_iter()
+                    .any(|terrain| tile_section_name(terrain) == section.name)
+                && section.unit_name().is_none()
+            {
+                return Err(RulesetError::UnknownSection { line: number });
+            }
+
+            sections.push(section);
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(RulesetError::Malformed { line: number })?;
+
+        sections
+            .last_mut()
+            .ok_or(RulesetError::Malformed { line: number })?
+            .entries
+            .push((key.trim(), value.trim(), number));
+    }
+
+    Ok(sections)
+}
+
+/// Returns the name of the section giving the stats of `terrain`
+fn tile_section_name(terrain: Terrain) -> String {
+    format!("tile {}", terrain.name())
+}
+
+fn victory_conditions(section: &Section) -> Result<VictoryConditions, RulesetError> {
+    Ok(VictoryConditions {
+        elimination: section.parse("elimination")?,
+        border_capture: section.parse("border-capture")?,
+        money_target: section.value("money-target", |value| match value {
+            NONE => Some(None),
+            value => value.parse().ok().map(Some),
+        })?,
+        objectives: section.value("objectives", |value| match value {
+            NONE => Some(None),
+            value => parse_objectives(value).map(Some),
+        })?,
+        turn_limit: section.value("turn-limit", |value| match value {
+            NONE => Some(None),
+            value => parse_turn_limit(value).map(Some),
+        })?,
+    })
+}
+
+/// Parses objectives written as the turns to hold them followed by `<rank>,<file>` coords
+fn parse_objectives(value: &str) -> Option<Objectives> {
+    let mut words = value.split_whitespace();
+    let turns = words.next()?.parse().ok()?;
+    let tiles = words
+        .map(|word| {
+            let (rank, file) = word.split_once(',')?;
+
+            Some(Coord::new(rank.parse().ok()?, file.parse().ok()?))
+        })
+        .collect::<Option<_>>()?;
+
+    Some(Objectives { tiles, turns })
+}
+
+fn parse_turn_limit(value: &str) -> Option<TurnLimit> {
+    let (turns, tiebreak) = value.split_once(' ')?;
+
+    Some(TurnLimit {
+        turns: turns.parse().ok()?,
+        tiebreak: parse_tiebreak(tiebreak.trim())?,
+    })
+}
+
+const fn tiebreak_name(tiebreak: Tiebreak) -> &'static str {
+    match tiebreak {
+        Tiebreak::None => NONE,
+        Tiebreak::Income => "income",
+        Tiebreak::Material => "material",
+    }
+}
+
+fn parse_tiebreak(name: &str) -> Option<Tiebreak> {
+    match name {
+        NONE => Some(Tiebreak::None),
+        "income" => Some(Tiebreak::Income),
+        "material" => Some(Tiebreak::Material),
+        _ => None,
+    }
+}
+
+fn unit_def(name: &str, section: &Section) -> Result<UnitDef, RulesetError> {
+    section.check_keys(&UNIT_KEYS)?;
+
+    Ok(UnitDef {
+        name: name.to_owned(),
+        kind: section.value("symbol", |value| {
+            let mut chars = value.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(symbol), None) => PieceType::from_symbol(symbol),
+                _ => None,
+            }
+        })?,
+        speed: section.parse("speed")?,
+        power: section.parse("power")?,
+        range: section.value("range", parse_range)?,
+        cost: section.parse("cost")?,
+        abilities: section.value("abilities", |value| {
+            value
+                .split_whitespace()
+                .map(Ability::from_name)
+                .collect::<Option<BTreeSet<_>>>()
+        })?,
+        move_costs: section.value("move-costs", |value| {
+            value
+                .split_whitespace()
+                .map(|word| {
+                    let (terrain, move_cost) = word.split_once(':')?;
+
+                    let move_cost = match move_cost {
+                        NONE => None,
+                        move_cost => Some(move_cost.parse().ok()?),
+                    };
+
+                    Some((Terrain::from_name(terrain)?, move_cost))
+                })
+                .collect::<Option<BTreeMap<_, _>>>()
+        })?,
+    })
+}
+
+fn move_cost_name(move_cost: Option<u8>) -> String {
+    move_cost.map_or_else(|| NONE.to_owned(), |move_cost| move_cost.to_string())
+}
+
+/// Parses a half-open range written as `<start>..<end>`, where `start` is at most `end`
+fn parse_range(value: &str) -> Option<Range<u8>> {
+    let (start, end) = value.split_once("..")?;
+    let range = start.trim().parse().ok()?..end.trim().parse().ok()?;
+
+    (range.start <= range.end).then_some(range)
+}
+
+/// The reason a ruleset could not be read or is unplayable
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RulesetError {
+    /// The line with the given one based number is not a section header, key or comment, or a
+    /// key comes before the first section
+    Malformed { line: usize },
+    /// The section headed on the line does not exist
+    UnknownSection { line: usize },
+    /// The section headed on the line was already given
+    DuplicateSection { line: usize },
+    /// The section is required but was not given
+    MissingSection(String),
+    /// The key on the line does not belong in its section
+    UnknownKey { line: usize, key: String },
+    /// The key on the line was already given in its section
+    DuplicateKey { line: usize, key: String },
+    /// The section does not give a value for the key
+    MissingKey { section: String, key: &'static str },
+    /// The value on the line could not be understood
+    InvalidValue { line: usize, key: &'static str },
+    /// A unit name is empty, has surrounding whitespace or contains control characters
+    InvalidName(String),
+    /// More than one unit has the name
+    DuplicateName(String),
+    /// More than one unit uses the symbol
+    DuplicateSymbol(char),
+    /// No units are defined
+    NoUnits,
+    /// A tile or unit gives a movement cost of zero
+    ZeroMoveCost,
+    /// The money target is not above the starting money, so both players would win at once
+    MoneyTargetReached,
+    /// The objectives have no tiles or need to be held for no turns
+    InvalidObjectives,
+    /// The turn limit ends the game before it starts
+    ZeroTurnLimit,
+}
+
+impl Display for RulesetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { line } => write!(f, "line {line} is malformed"),
+            Self::UnknownSection { line } => write!(f, "unknown section on line {line}"),
+            Self::DuplicateSection { line } => {
+                write!(f, "section on line {line} was already given")
+            }
+            Self::MissingSection(name) => write!(f, "section {name:?} is missing"),
+            Self::UnknownKey { line, key } => write!(f, "unknown key {key:?} on line {line}"),
+            Self::DuplicateKey { line, key } => {
+                write!(f, "key {key:?} on line {line} was already given")
+            }
+            Self::MissingKey { section, key } => {
+                write!(f, "section {section:?} is missing key {key:?}")
+            }
+            Self::InvalidValue { line, key } => {
+                write!(f, "invalid value for key {key:?} on line {line}")
+            }
+            Self::InvalidName(name) => write!(f, "invalid unit name {name:?}"),
+            Self::DuplicateName(name) => write!(f, "more than one unit is named {name:?}"),
+            Self::DuplicateSymbol(symbol) => {
+                write!(f, "more than one unit uses the symbol {symbol:?}")
+            }
+            Self::NoUnits => write!(f, "no units are defined"),
+            Self::ZeroMoveCost => write!(f, "movement costs must be above zero"),
+            Self::MoneyTargetReached => {
+                write!(f, "money target is not above the starting money")
+            }
+            Self::InvalidObjectives => {
+                write!(
+                    f,
+                    "objectives need tiles and at least one turn to hold them"
+                )
+            }
+            Self::ZeroTurnLimit => write!(f, "turn limit must allow at least one turn"),
+        }
+    }
+}
+
+impl Error for RulesetError {}
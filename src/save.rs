@@ -0,0 +1,721 @@
+use crate::{
+    Game,
+    actions::{BattleActor, Command, LoggedCommand},
+    battle::PendingBattle,
+    board::{Board, BoardError, HOLE_SYMBOL},
+    coordinates::{Coord, Coordinate, PieceCoord, TileCoord},
+    history::{History, UndoPolicy},
+    phase::Phase,
+    piece::{Actions, BattleAction, Piece, PieceId, PieceType},
+    player::Player,
+    ruleset::{Ruleset, RulesetError},
+    unit::Units,
+    victory::GameStatus,
+};
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::{self, Read, Write},
+    str::FromStr,
+    sync::Arc,
+};
+
+/// The first word of every save file
+const MAGIC: &str = "convoy-save";
+/// The version of the save format written by [`Game::save`]
+pub const FORMAT_VERSION: u32 = 10;
+
+impl Game {
+    /// Writes the complete state of the game to `writer`
+    ///
+    /// The save format is line based: a `convoy-save <version>` header, followed by the
+    /// current player, phase, turn, money, undo policy and pending battle, the ruleset in the
+    /// ruleset text format, progress towards the victory conditions, the next piece id, the
+    /// board tiles in the board text format, one line per piece and per captured tile, and
+    /// finally one line per logged command. The undo history is not saved, and the
+    /// game status is worked out again when loading.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn save(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "{MAGIC} {FORMAT_VERSION}")?;
+        writeln!(writer, "player {}", player_name(self.current_player))?;
+        writeln!(writer, "phase {}", phase_name(self.phase))?;
+        writeln!(writer, "turn {}", self.turn)?;
+        writeln!(writer, "money {} {}", self.money[0], self.money[1])?;
+        writeln!(
+            writer,
+            "undo {} {}",
+            u8::from(self.undo_policy.battles),
+            u8::from(self.undo_policy.end_turn)
+        )?;
+
+        match &self.pending_battle {
+            Some(pending) => writeln!(
+                writer,
+                "pending {}",
+                command_words(&Command::Battle {
+                    target: pending.target,
+                    initiator: pending.initiator,
+                    attack_supporters: pending.attack_supporters.clone(),
+                })
+                .join(" ")
+            )?,
+            None => writeln!(writer, "pending none")?,
+        }
+
+        let ruleset = self.ruleset.to_string();
+
+        writeln!(writer, "ruleset {}", ruleset.lines().count())?;
+        write!(writer, "{ruleset}")?;
+
+        writeln!(
+            writer,
+            "fielded {} {}",
+            u8::from(self.has_fielded[0]),
+            u8::from(self.has_fielded[1])
+        )?;
+        writeln!(
+            writer,
+            "objective-turns {} {}",
+            self.objective_turns[0], self.objective_turns[1]
+        )?;
+        writeln!(writer, "next-piece-id {}", self.next_piece_id)?;
+
+        writeln!(
+            writer,
+            "board {} {}",
+            self.board.ranks(),
+            self.board.files()
+        )?;
+
+        for rank in 0..self.board.ranks() {
+            let line: String = (0..self.board.files())
+                .map(|file| {
+                    self.board
+                        .get(Coord::new(rank, file))
+                        .map_or(HOLE_SYMBOL, |tile| tile.kind.symbol())
+                })
+                .collect();
+
+            writeln!(writer, "{line}")?;
+        }
+
+        let pieces: Vec<_> = self.board.piece_coords().collect();
+
+        writeln!(writer, "pieces {}", pieces.len())?;
+
+        for coord in pieces {
+            if let Some(piece) = self.board[coord].piece_option {
+                writeln!(
+                    writer,
+                    "{} {} {} {} {}",
+                    coord.rank(),
+                    coord.file(),
+                    piece.symbol(),
+                    actions_word(piece.actions),
+                    piece.id().get()
+                )?;
+            }
+        }
+
+        let captures: Vec<_> = self
+            .board
+            .tile_coords()
+            .filter_map(|coord| Some((coord, self.board[coord].captured_by?)))
+            .collect();
+
+        writeln!(writer, "captures {}", captures.len())?;
+
+        for (coord, player) in captures {
+            writeln!(
+                writer,
+                "{} {} {}",
+                coord.rank(),
+                coord.file(),
+                player_name(player)
+            )?;
+        }
+
+        writeln!(writer, "log {}", self.log.len())?;
+
+        for logged in &self.log {
+            writeln!(
+                writer,
+                "{} {} {}",
+                logged.turn,
+                player_name(logged.player),
+                command_words(&logged.command).join(" ")
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a game written by [`Self::save`]
+    ///
+    /// # Errors
+    ///
+    /// Fails if `reader` fails, if the data was written by an incompatible version, or if it
+    /// does not describe a valid game.
+    pub fn load(mut reader: impl Read) -> Result<Self, LoadError> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text).map_err(LoadError::Io)?;
+
+        let mut lines = Lines::new(&text);
+
+        let version = lines.field(MAGIC).map_err(|_| LoadError::NotASave)?;
+        let version: u32 = lines.parse(version)?;
+
+        if version != FORMAT_VERSION {
+            return Err(LoadError::UnsupportedVersion(version));
+        }
+
+        let current_player = lines.field("player")?;
+        let current_player = lines.parse_with(current_player, parse_player)?;
+        let phase = lines.field("phase")?;
+        let phase = lines.parse_with(phase, parse_phase)?;
+        let turn = lines.field("turn")?;
+        let turn = lines.parse(turn)?;
+        let money = lines.field("money")?;
+        let money = lines.parse_words::<u8, 2>(money)?;
+        let undo_policy = lines.field("undo")?;
+        let [battles, end_turn] = lines.split::<2>(undo_policy)?;
+        let undo_policy = UndoPolicy {
+            battles: lines.parse_with(battles, parse_flag)?,
+            end_turn: lines.parse_with(end_turn, parse_flag)?,
+        };
+        let pending_battle = match lines.field("pending")? {
+            "none" => None,
+            pending_battle => Some(lines.parse_with(pending_battle, parse_pending_battle)?),
+        };
+        let pending_line = lines.number;
+        let ruleset = lines.ruleset()?;
+        let has_fielded = lines.field("fielded")?;
+        let [fielded_1, fielded_2] = lines.split::<2>(has_fielded)?;
+        let has_fielded = [
+            lines.parse_with(fielded_1, parse_flag)?,
+            lines.parse_with(fielded_2, parse_flag)?,
+        ];
+        let objective_turns = lines.field("objective-turns")?;
+        let objective_turns = lines.parse_words::<u32, 2>(objective_turns)?;
+        let next_piece_id = lines.field("next-piece-id")?;
+        let next_piece_id = lines.parse(next_piece_id)?;
+        let board = lines.board(next_piece_id, &ruleset.units)?;
+        let log = lines.log()?;
+
+        if pending_battle
+            .as_ref()
+            .is_some_and(|pending| !is_valid_pending_battle(pending, &board, current_player))
+        {
+            return Err(LoadError::Malformed { line: pending_line });
+        }
+
+        if lines.lines.any(|line| !line.trim().is_empty()) {
+            return Err(LoadError::TrailingData);
+        }
+
+        let mut game = Self {
+            board,
+            current_player,
+            phase,
+            turn,
+            money,
+            pending_battle,
+            log,
+            history: History::new(),
+            undo_policy,
+            ruleset: Arc::new(ruleset),
+            status: GameStatus::Ongoing,
+            has_fielded,
+            objective_turns,
+            next_piece_id,
+        };
+
+        game.status = game.evaluate_status();
+
+        Ok(game)
+    }
+}
+
+/// The reason a saved game could not be loaded
+#[derive(Debug)]
+pub enum LoadError {
+    /// Reading the data failed
+    Io(io::Error),
+    /// The data does not start with a save header
+    NotASave,
+    /// The data was saved in a different version of the format
+    UnsupportedVersion(u32),
+    /// The line with the given one based number could not be understood
+    Malformed { line: usize },
+    /// The data ended before the game was complete
+    UnexpectedEnd,
+    /// Unexpected lines follow the game
+    TrailingData,
+    /// The board tiles are invalid
+    Board(BoardError),
+    /// The ruleset is invalid
+    Ruleset(RulesetError),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read save: {error}"),
+            Self::NotASave => write!(f, "data is not a convoy save"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "save format version {version} is not supported, expected {FORMAT_VERSION}"
+            ),
+            Self::Malformed { line } => write!(f, "line {line} of the save is malformed"),
+            Self::UnexpectedEnd => write!(f, "save ended unexpectedly"),
+            Self::TrailingData => write!(f, "save has unexpected trailing data"),
+            Self::Board(error) => write!(f, "saved board is invalid: {error}"),
+            Self::Ruleset(error) => write!(f, "saved ruleset is invalid: {error}"),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Board(error) => Some(error),
+            Self::Ruleset(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A cursor over the lines of a save that tracks line numbers for error reporting
+struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    number: usize,
+}
+
+impl<'a> Lines<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            lines: text.lines(),
+            number: 0,
+        }
+    }
+
+    const fn malformed(&self) -> LoadError {
+        LoadError::Malformed { line: self.number }
+    }
+
+    fn next(&mut self) -> Result<&'a str, LoadError> {
+        self.number += 1;
+        self.lines
+            .next()
+            .map(|line| line.trim_end_matches('\r'))
+            .ok_or(LoadError::UnexpectedEnd)
+    }
+
+    /// Reads a `<key> <value>` line and returns the value
+    fn field(&mut self, key: &str) -> Result<&'a str, LoadError> {
+        let line = self.next()?;
+
+        line.strip_prefix(key)
+            .and_then(|value| value.strip_prefix(' '))
+            .ok_or_else(|| self.malformed())
+    }
+
+    fn split<const N: usize>(&self, value: &'a str) -> Result<[&'a str; N], LoadError> {
+        let words: Vec<_> = value.split(' ').collect();
+
+        words.try_into().map_err(|_| self.malformed())
+    }
+
+    fn parse<T: FromStr>(&self, value: &str) -> Result<T, LoadError> {
+        value.parse().map_err(|_| self.malformed())
+    }
+
+    fn parse_with<T>(
+        &self,
+        value: &str,
+        f: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, LoadError> {
+        f(value).ok_or_else(|| self.malformed())
+    }
+
+    fn parse_words<T: FromStr, const N: usize>(&self, value: &'a str) -> Result<[T; N], LoadError> {
+        let words = self.split::<N>(value)?;
+        let values: Vec<T> = words
+            .into_iter()
+            .map(|word| self.parse(word))
+            .collect::<Result<_, _>>()?;
+
+        values.try_into().map_err(|_| self.malformed())
+    }
+
+    /// Reads the ruleset
+    fn ruleset(&mut self) -> Result<Ruleset, LoadError> {
+        let count = self.field("ruleset")?;
+        let count: usize = self.parse(count)?;
+        let mut ruleset = String::new();
+
+        for _ in 0..count {
+            ruleset.push_str(self.next()?);
+            ruleset.push('\n');
+        }
+
+        ruleset.parse().map_err(LoadError::Ruleset)
+    }
+
+    /// Reads the board tiles followed by the pieces and captured tiles on them
+    ///
+    /// Piece ids must be unique and below `next_piece_id`, and piece types must be defined by
+    /// `units`.
+    fn board(&mut self, next_piece_id: u32, units: &Units) -> Result<Board, LoadError> {
+        let size = self.field("board")?;
+        let [ranks, files] = self.parse_words::<u8, 2>(size)?;
+        let mut tiles = String::new();
+
+        for _ in 0..ranks {
+            let rank = self.next()?;
+
+            if rank.chars().count() != usize::from(files) {
+                return Err(self.malformed());
+            }
+
+            tiles.push_str(rank);
+            tiles.push('\n');
+        }
+
+        let mut board: Board = tiles.parse().map_err(LoadError::Board)?;
+
+        if (board.ranks(), board.files()) != (ranks, files) {
+            return Err(self.malformed());
+        }
+
+        let count = self.field("pieces")?;
+        let count: usize = self.parse(count)?;
+        let mut ids = HashSet::new();
+
+        for _ in 0..count {
+            let line = self.next()?;
+            let [rank, file, symbol, actions, id] = self.split::<5>(line)?;
+            let coord = Coord::new(self.parse(rank)?, self.parse(file)?);
+            let mut piece = self.parse_with(symbol, |symbol| {
+                let mut chars = symbol.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(symbol), None) => Piece::from_symbol(symbol),
+                    _ => None,
+                }
+            })?;
+
+            if units.get(piece.kind()).is_none() {
+                return Err(self.malformed());
+            }
+
+            piece.actions = self.parse_with(actions, parse_actions)?;
+
+            let id = self.parse(id)?;
+
+            if id >= next_piece_id || !ids.insert(id) {
+                return Err(self.malformed());
+            }
+
+            let piece = piece.with_id(PieceId(id));
+            let tile = board.get_mut(coord).ok_or_else(|| self.malformed())?;
+
+            if tile.piece_option.replace(piece).is_some() {
+                return Err(self.malformed());
+            }
+        }
+
+        let count = self.field("captures")?;
+        let count: usize = self.parse(count)?;
+
+        for _ in 0..count {
+            let line = self.next()?;
+            let [rank, file, player] = self.split::<3>(line)?;
+            let coord = Coord::new(self.parse(rank)?, self.parse(file)?);
+            let player = self.parse_with(player, parse_player)?;
+            let tile = board.get_mut(coord).ok_or_else(|| self.malformed())?;
+
+            if !tile.is_capturable() {
+                return Err(self.malformed());
+            }
+
+            tile.capture(player);
+        }
+
+        Ok(board)
+    }
+
+    fn log(&mut self) -> Result<Vec<LoggedCommand>, LoadError> {
+        let count = self.field("log")?;
+        let count: usize = self.parse(count)?;
+        let mut log = vec![];
+
+        for _ in 0..count {
+            let line = self.next()?;
+            let mut words = line.split(' ');
+            let turn = self.parse(words.next().unwrap_or_default())?;
+            let player = self.parse_with(words.next().unwrap_or_default(), parse_player)?;
+            let command = parse_command(&mut words).ok_or_else(|| self.malformed())?;
+
+            if words.next().is_some() {
+                return Err(self.malformed());
+            }
+
+            log.push(LoggedCommand {
+                turn,
+                player,
+                command,
+            });
+        }
+
+        Ok(log)
+    }
+}
+
+/// Returns whether the target and every attacker of `pending` are pieces of the right player
+fn is_valid_pending_battle(pending: &PendingBattle, board: &Board, current_player: Player) -> bool {
+    let owner = |coord: PieceCoord| {
+        board
+            .get(coord.as_coord())
+            .and_then(|tile| tile.piece_option)
+            .map(Piece::owner)
+    };
+
+    owner(pending.target) == Some(-current_player)
+        && pending
+            .attacker_coords()
+            .all(|coord| owner(coord) == Some(current_player))
+}
+
+/// Parses a pending battle encoded as a battle command
+fn parse_pending_battle(value: &str) -> Option<PendingBattle> {
+    let mut words = value.split(' ');
+
+    match (parse_command(&mut words)?, words.next()) {
+        (
+            Command::Battle {
+                target,
+                initiator,
+                attack_supporters,
+            },
+            None,
+        ) => Some(PendingBattle {
+            target,
+            initiator,
+            attack_supporters,
+        }),
+        _ => None,
+    }
+}
+
+/// Encodes `actions` as the letters of the actions taken, in the order `m`oved, `a`ttacked,
+/// `s`upported and `r`ecruited, or `-` if the piece is ready
+fn actions_word(actions: Actions) -> String {
+    let word: String = [
+        (actions.moved, 'm'),
+        (actions.battle == Some(BattleAction::Attacked), 'a'),
+        (actions.battle == Some(BattleAction::Supported), 's'),
+        (actions.recruited, 'r'),
+    ]
+    .into_iter()
+    .filter_map(|(taken, letter)| taken.then_some(letter))
+    .collect();
+
+    if word.is_empty() {
+        "-".to_owned()
+    } else {
+        word
+    }
+}
+
+fn parse_actions(word: &str) -> Option<Actions> {
+    if word == "-" {
+        return Some(Actions::READY);
+    }
+
+    let actions = Actions {
+        moved: word.contains('m'),
+        battle: if word.contains('a') {
+            Some(BattleAction::Attacked)
+        } else if word.contains('s') {
+            Some(BattleAction::Supported)
+        } else {
+            None
+        },
+        recruited: word.contains('r'),
+    };
+
+    (actions_word(actions) == word).then_some(actions)
+}
+
+fn parse_flag(flag: &str) -> Option<bool> {
+    match flag {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None,
+    }
+}
+
+const fn player_name(player: Player) -> &'static str {
+    match player {
+        Player::P1 => "1",
+        Player::P2 => "2",
+    }
+}
+
+fn parse_player(name: &str) -> Option<Player> {
+    match name {
+        "1" => Some(Player::P1),
+        "2" => Some(Player::P2),
+        _ => None,
+    }
+}
+
+const fn phase_name(phase: Phase) -> &'static str {
+    match phase {
+        Phase::Income => "income",
+        Phase::Command => "command",
+        Phase::Resupply => "resupply",
+    }
+}
+
+/// Parses the phase of a saved game, which can only be the command phase since the others
+/// run while a turn ends
+fn parse_phase(name: &str) -> Option<Phase> {
+    (name == phase_name(Phase::Command)).then_some(Phase::Command)
+}
+
+fn coord_words(coord: impl Coordinate) -> [String; 2] {
+    [coord.rank().to_string(), coord.file().to_string()]
+}
+
+fn actor_words(actor: BattleActor) -> Vec<String> {
+    match actor {
+        BattleActor::Static { coord } => {
+            [vec!["s".to_owned()], coord_words(coord).to_vec()].concat()
+        }
+        BattleActor::Moving { from, to } => [
+            vec!["m".to_owned()],
+            coord_words(from).to_vec(),
+            coord_words(to).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+fn actors_words(actors: &[BattleActor]) -> Vec<String> {
+    std::iter::once(actors.len().to_string())
+        .chain(actors.iter().flat_map(|actor| actor_words(*actor)))
+        .collect()
+}
+
+/// Encodes `command` as space separated words
+fn command_words(command: &Command) -> Vec<String> {
+    match command {
+        Command::Move { from, to } => [
+            vec!["move".to_owned()],
+            coord_words(*from).to_vec(),
+            coord_words(*to).to_vec(),
+        ]
+        .concat(),
+        Command::Recruit { piece_type, coord } => [
+            vec!["recruit".to_owned(), piece_type.symbol().to_string()],
+            coord_words(*coord).to_vec(),
+        ]
+        .concat(),
+        Command::Battle {
+            target,
+            initiator,
+            attack_supporters,
+        } => [
+            vec!["battle".to_owned()],
+            coord_words(*target).to_vec(),
+            actor_words(*initiator),
+            actors_words(attack_supporters),
+        ]
+        .concat(),
+        Command::Defend {
+            target_is_defending,
+            defence_supporters,
+        } => [
+            vec!["defend".to_owned()],
+            vec![u8::from(*target_is_defending).to_string()],
+            actors_words(defence_supporters),
+        ]
+        .concat(),
+        Command::EndTurn => vec!["end".to_owned()],
+    }
+}
+
+fn parse_coord<'a>(words: &mut impl Iterator<Item = &'a str>) -> Option<Coord> {
+    Some(Coord::new(
+        words.next()?.parse().ok()?,
+        words.next()?.parse().ok()?,
+    ))
+}
+
+fn parse_tile_coord<'a>(words: &mut impl Iterator<Item = &'a str>) -> Option<TileCoord> {
+    parse_coord(words).map(TileCoord::new_unchecked)
+}
+
+fn parse_piece_coord<'a>(words: &mut impl Iterator<Item = &'a str>) -> Option<PieceCoord> {
+    parse_coord(words).map(PieceCoord::new_unchecked)
+}
+
+fn parse_actor<'a>(words: &mut impl Iterator<Item = &'a str>) -> Option<BattleActor> {
+    match words.next()? {
+        "s" => Some(BattleActor::Static {
+            coord: parse_piece_coord(words)?,
+        }),
+        "m" => Some(BattleActor::Moving {
+            from: parse_piece_coord(words)?,
+            to: parse_tile_coord(words)?,
+        }),
+        _ => None,
+    }
+}
+
+fn parse_actors<'a>(words: &mut impl Iterator<Item = &'a str>) -> Option<Vec<BattleActor>> {
+    let count: usize = words.next()?.parse().ok()?;
+
+    (0..count).map(|_| parse_actor(words)).collect()
+}
+
+/// Decodes a command encoded by [`command_words`]
+fn parse_command<'a>(words: &mut impl Iterator<Item = &'a str>) -> Option<Command> {
+    match words.next()? {
+        "move" => Some(Command::Move {
+            from: parse_piece_coord(words)?,
+            to: parse_tile_coord(words)?,
+        }),
+        "recruit" => {
+            let mut symbol = words.next()?.chars();
+            let piece_type = match (symbol.next(), symbol.next()) {
+                (Some(symbol), None) => PieceType::from_symbol(symbol)?,
+                _ => return None,
+            };
+
+            Some(Command::Recruit {
+                piece_type,
+                coord: parse_tile_coord(words)?,
+            })
+        }
+        "battle" => Some(Command::Battle {
+            target: parse_piece_coord(words)?,
+            initiator: parse_actor(words)?,
+            attack_supporters: parse_actors(words)?,
+        }),
+        "defend" => Some(Command::Defend {
+            target_is_defending: parse_flag(words.next()?)?,
+            defence_supporters: parse_actors(words)?,
+        }),
+        "end" => Some(Command::EndTurn),
+        _ => None,
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    board::Board, coordinates::Coord, player::Player, ruleset::Ruleset, tile::Tile, unit::UnitDef,
+};
+use std::collections::HashSet;
+
+/// Returns the coords of every tile supplied to `player`
+///
+/// Border tiles controlled by `player` are supply sources, as is every piece of `player` whose
+/// unit in `ruleset` [is a supplier](UnitDef::is_supplier) standing on a supplied tile. Each
+/// source supplies all tiles within the [supply radius](Ruleset::supply_radius), so suppliers
+/// can chain supplies outwards from the border. Supply reaching a road
+/// [carries](crate::tile::Tile::carries_supply) along every road joined to it.
+#[must_use]
+pub fn supplied_coords(board: &Board, ruleset: &Ruleset, player: Player) -> HashSet<Coord> {
+    let mut supplied = HashSet::new();
+    let mut sources: Vec<Coord> = board
+        .tile_coords()
+        .filter(|coord| board[*coord].is_supply_source(player))
+        .collect();
+    let mut visited_sources: HashSet<Coord> = sources.iter().copied().collect();
+
+    while let Some(source) = sources.pop() {
+        let mut pending: Vec<Coord> = board
+            .coords_within(source, u16::from(ruleset.supply_radius))
+            .collect();
+
+        while let Some(coord) = pending.pop() {
+            if !supplied.insert(coord) {
+                continue;
+            }
+
+            if board[coord].carries_supply() {
+                pending.extend(
+                    coord
+                        .adjacent()
+                        .into_iter()
+                        .filter(|next| board.get(*next).is_some_and(Tile::carries_supply)),
+                );
+            }
+
+            if board[coord].piece_option.is_some_and(|piece| {
+                piece.owner() == player
+                    && ruleset
+                        .units
+                        .get(piece.kind())
+                        .is_some_and(UnitDef::is_supplier)
+            }) && visited_sources.insert(coord)
+            {
+                sources.push(coord);
+            }
+        }
+    }
+
+    supplied
+}
@@ -1,37 +1,87 @@
-use crate::{piece::Piece, player::Player};
+use crate::{piece::Piece, player::Player, ruleset::Ruleset, unit::UnitDef};
+use std::ops::{Index, IndexMut};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Tile {
     pub kind: TileType,
     pub piece_option: Option<Piece>,
+    /// The player who last occupied the tile, only tracked for capturable tiles
+    pub captured_by: Option<Player>,
 }
 
 impl Tile {
     #[must_use]
-    pub const fn income_bonus(self) -> u8 {
-        match self.kind {
-            TileType::Empty | TileType::Border(_) => 0,
-            TileType::Town => 1,
-            TileType::City => 3,
+    pub const fn new(kind: TileType) -> Self {
+        Self {
+            kind,
+            piece_option: None,
+            captured_by: None,
         }
     }
 
+    /// Returns the player controlling the tile, if any
     #[must_use]
-    pub const fn defence_bonus(self) -> u8 {
+    pub const fn controller(self) -> Option<Player> {
         match self.kind {
-            TileType::Empty | TileType::Border(_) => 0,
-            TileType::Town => 1,
-            TileType::City => 2,
+            TileType::Town | TileType::City => self.captured_by,
+            TileType::Border(controller) => Some(controller),
+            _ => None,
         }
     }
 
     #[must_use]
-    pub fn can_recruit(self, player: Player) -> bool {
-        match self.kind {
-            TileType::Empty | TileType::Town | TileType::City => false,
-            TileType::Border(controller) => player == controller,
+    pub const fn is_capturable(self) -> bool {
+        matches!(self.kind, TileType::Town | TileType::City)
+    }
+
+    /// Gives control of the tile to `player` if it can be captured
+    pub const fn capture(&mut self, player: Player) {
+        if self.is_capturable() {
+            self.captured_by = Some(player);
         }
     }
+
+    /// Returns the income the tile earns its controller under `ruleset`
+    #[must_use]
+    pub const fn income_bonus(self, ruleset: &Ruleset) -> u8 {
+        ruleset.tile_stats(self.kind).income
+    }
+
+    /// Returns the defence bonus of a piece defending itself on the tile under `ruleset`
+    #[must_use]
+    pub const fn defence_bonus(self, ruleset: &Ruleset) -> u8 {
+        ruleset.tile_stats(self.kind).defence
+    }
+
+    /// Returns the movement points a piece of `unit` spends entering the tile under `ruleset`,
+    /// or `None` if it cannot enter the tile
+    ///
+    /// The unit's own [movement costs](UnitDef::move_costs) replace those of the ruleset.
+    #[must_use]
+    pub fn move_cost(self, ruleset: &Ruleset, unit: &UnitDef) -> Option<u8> {
+        let terrain = self.kind.terrain();
+
+        unit.move_costs
+            .get(&terrain)
+            .copied()
+            .unwrap_or_else(|| ruleset.terrain_stats(terrain).move_cost)
+    }
+
+    #[must_use]
+    pub fn can_recruit(self, player: Player) -> bool {
+        self.kind == TileType::Border(player)
+    }
+
+    #[must_use]
+    pub fn is_supply_source(self, player: Player) -> bool {
+        self.kind == TileType::Border(player)
+    }
+
+    /// Whether supply reaching the tile travels on to the tiles joined to it by road
+    #[must_use]
+    pub fn carries_supply(self) -> bool {
+        self.kind == TileType::Road
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -40,4 +90,131 @@ pub enum TileType {
     Town,
     City,
     Border(Player),
+    Forest,
+    Mountain,
+    River,
+    Road,
+    Sea,
+}
+
+impl TileType {
+    /// Returns the terrain of the tile type, which is the same for both players' borders
+    #[must_use]
+    pub const fn terrain(self) -> Terrain {
+        match self {
+            Self::Empty => Terrain::Empty,
+            Self::Town => Terrain::Town,
+            Self::City => Terrain::City,
+            Self::Border(_) => Terrain::Border,
+            Self::Forest => Terrain::Forest,
+            Self::Mountain => Terrain::Mountain,
+            Self::River => Terrain::River,
+            Self::Road => Terrain::Road,
+            Self::Sea => Terrain::Sea,
+        }
+    }
+
+    /// Returns the character representing the tile type in the board text format
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Town => 't',
+            Self::City => 'c',
+            Self::Border(Player::P1) => '1',
+            Self::Border(Player::P2) => '2',
+            Self::Forest => 'f',
+            Self::Mountain => 'm',
+            Self::River => 'r',
+            Self::Road => '=',
+            Self::Sea => '~',
+        }
+    }
+
+    #[must_use]
+    pub const fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '.' => Some(Self::Empty),
+            't' => Some(Self::Town),
+            'c' => Some(Self::City),
+            '1' => Some(Self::Border(Player::P1)),
+            '2' => Some(Self::Border(Player::P2)),
+            'f' => Some(Self::Forest),
+            'm' => Some(Self::Mountain),
+            'r' => Some(Self::River),
+            '=' => Some(Self::Road),
+            '~' => Some(Self::Sea),
+            _ => None,
+        }
+    }
+}
+
+/// A type of tile regardless of who it belongs to, which the rules of a game are given for
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Terrain {
+    Empty,
+    Town,
+    City,
+    Border,
+    Forest,
+    Mountain,
+    River,
+    Road,
+    Sea,
+}
+
+impl Terrain {
+    /// Every terrain, in declaration order
+    pub const ALL: [Self; 9] = [
+        Self::Empty,
+        Self::Town,
+        Self::City,
+        Self::Border,
+        Self::Forest,
+        Self::Mountain,
+        Self::River,
+        Self::Road,
+        Self::Sea,
+    ];
+
+    /// Returns the name of the terrain in the [ruleset text format](Ruleset)
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::Town => "town",
+            Self::City => "city",
+            Self::Border => "border",
+            Self::Forest => "forest",
+            Self::Mountain => "mountain",
+            Self::River => "river",
+            Self::Road => "road",
+            Self::Sea => "sea",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|terrain| terrain.name() == name)
+    }
+
+    /// Returns the position of the terrain in [`Self::ALL`]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl<T> Index<Terrain> for [T; 9] {
+    type Output = T;
+
+    fn index(&self, index: Terrain) -> &Self::Output {
+        &self[index.index()]
+    }
+}
+
+impl<T> IndexMut<Terrain> for [T; 9] {
+    fn index_mut(&mut self, index: Terrain) -> &mut Self::Output {
+        &mut self[index.index()]
+    }
 }
@@ -0,0 +1,196 @@
+use crate::{piece::PieceType, ruleset::RulesetError, tile::Terrain};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    ops::{Index, Range},
+};
+
+/// The stats and abilities of a type of piece
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnitDef {
+    /// The name shown to players
+    pub name: String,
+    pub kind: PieceType,
+    /// The movement points the unit can spend in a turn
+    pub speed: u8,
+    /// What the unit adds to a battle it takes part in
+    pub power: u8,
+    /// The distances from which the unit can attack or support
+    pub range: Range<u8>,
+    /// What recruiting the unit costs
+    pub cost: u8,
+    pub abilities: BTreeSet<Ability>,
+    /// The movement costs of terrain for the unit where they differ from the ruleset, with
+    /// `None` for terrain the unit cannot enter
+    pub move_costs: BTreeMap<Terrain, Option<u8>>,
+}
+
+impl UnitDef {
+    #[must_use]
+    pub fn has(&self, ability: Ability) -> bool {
+        self.abilities.contains(&ability)
+    }
+
+    #[must_use]
+    pub fn can_initiate(&self) -> bool {
+        self.has(Ability::Initiate)
+    }
+
+    #[must_use]
+    pub fn can_defend(&self) -> bool {
+        self.has(Ability::Defend)
+    }
+
+    /// Whether the unit can support a battle on the given side, moving into range first if
+    /// `is_moving`
+    #[must_use]
+    pub fn can_support(&self, is_attacking: bool, is_moving: bool) -> bool {
+        match (is_moving, is_attacking) {
+            (false, _) => self.has(Ability::Support),
+            (true, true) => self.has(Ability::MoveToAttack),
+            (true, false) => self.has(Ability::MoveToDefend),
+        }
+    }
+
+    /// Whether the unit supplies the tiles around it while supplied itself
+    #[must_use]
+    pub fn is_supplier(&self) -> bool {
+        self.has(Ability::Supply)
+    }
+
+    #[must_use]
+    pub fn disbands_unsupplied(&self) -> bool {
+        !self.has(Ability::SelfSufficient)
+    }
+
+    /// Whether supporting a battle leaves the unit free to support further battles
+    #[must_use]
+    pub fn supports_freely(&self) -> bool {
+        self.has(Ability::SupportFreely)
+    }
+
+    /// Returns whether a target `distance` tiles away is within the range of the unit
+    #[must_use]
+    pub fn in_range(&self, distance: u16) -> bool {
+        u8::try_from(distance).is_ok_and(|distance| self.range.contains(&distance))
+    }
+}
+
+/// Something a unit can do beyond moving
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Ability {
+    /// The unit can initiate battles
+    Initiate,
+    /// The unit can defend itself when attacked
+    Defend,
+    /// The unit can support battles from where it stands
+    Support,
+    /// The unit can move into range to initiate or support an attack
+    MoveToAttack,
+    /// The unit can move into range to support a defence
+    MoveToDefend,
+    /// The unit supplies the tiles around it while supplied itself
+    Supply,
+    /// The unit is not disbanded when unsupplied
+    SelfSufficient,
+    /// Supporting a battle leaves the unit free to support further battles
+    SupportFreely,
+}
+
+impl Ability {
+    pub const ALL: [Self; 8] = [
+        Self::Initiate,
+        Self::Defend,
+        Self::Support,
+        Self::MoveToAttack,
+        Self::MoveToDefend,
+        Self::Supply,
+        Self::SelfSufficient,
+        Self::SupportFreely,
+    ];
+
+    /// Returns the name of the ability in the [ruleset text format](crate::ruleset::Ruleset)
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Initiate => "initiate",
+            Self::Defend => "defend",
+            Self::Support => "support",
+            Self::MoveToAttack => "move-to-attack",
+            Self::MoveToDefend => "move-to-defend",
+            Self::Supply => "supply",
+            Self::SelfSufficient => "self-sufficient",
+            Self::SupportFreely => "support-freely",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|ability| ability.name() == name)
+    }
+}
+
+/// The unit definitions of a game, at most one per piece type
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Units {
+    defs: Vec<UnitDef>,
+}
+
+impl Units {
+    /// Creates a set of unit definitions
+    ///
+    /// # Errors
+    ///
+    /// Names must be non-empty without surrounding whitespace, and no two units can share a
+    /// name or piece type.
+    pub fn new(defs: Vec<UnitDef>) -> Result<Self, RulesetError> {
+        let mut names = HashSet::new();
+        let mut kinds = HashSet::new();
+
+        for def in &defs {
+            if def.name.is_empty()
+                || def.name.trim() != def.name
+                || def.name.contains(char::is_control)
+            {
+                return Err(RulesetError::InvalidName(def.name.clone()));
+            }
+
+            if !names.insert(def.name.as_str()) {
+                return Err(RulesetError::DuplicateName(def.name.clone()));
+            }
+
+            if !kinds.insert(def.kind) {
+                return Err(RulesetError::DuplicateSymbol(def.kind.symbol()));
+            }
+        }
+
+        Ok(Self { defs })
+    }
+
+    /// Returns the definition of `kind`, if it has one
+    #[must_use]
+    pub fn get(&self, kind: PieceType) -> Option<&UnitDef> {
+        self.defs.iter().find(|def| def.kind == kind)
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.defs.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &UnitDef> {
+        self.defs.iter()
+    }
+
+    /// Returns every defined piece type, in definition order
+    pub fn types(&self) -> impl Iterator<Item = PieceType> {
+        self.defs.iter().map(|def| def.kind)
+    }
+}
+
+impl Index<PieceType> for Units {
+    type Output = UnitDef;
+
+    fn index(&self, index: PieceType) -> &Self::Output {
+        self.get(index).expect("Indexed undefined piece type")
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{Game, coordinates::Coord, piece::Piece, player::Player, tile::TileType};
+use std::fmt::{self, Display, Formatter};
+
+/// The ways a match can end
+///
+/// Conditions are checked after every command. If both players win at once the game is
+/// drawn.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VictoryConditions {
+    /// A player wins once their opponent has lost every piece they fielded
+    pub elimination: bool,
+    /// A player wins once their pieces occupy every border tile of their opponent
+    pub border_capture: bool,
+    /// A player wins once they have at least this much money
+    pub money_target: Option<u8>,
+    /// A player wins once they have occupied every objective tile at the end of this many of
+    /// their consecutive turns
+    pub objectives: Option<Objectives>,
+    /// The game ends once this many turns have been played
+    pub turn_limit: Option<TurnLimit>,
+}
+
+impl VictoryConditions {
+    /// Creates the standard conditions, where a player wins by elimination or by capturing
+    /// the borders of their opponent
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            elimination: true,
+            border_capture: true,
+            money_target: None,
+            objectives: None,
+            turn_limit: None,
+        }
+    }
+}
+
+impl Default for VictoryConditions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Objectives {
+    pub tiles: Vec<Coord>,
+    pub turns: u32,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TurnLimit {
+    /// The last turn played, counting each player's turn separately
+    pub turns: u32,
+    pub tiebreak: Tiebreak,
+}
+
+/// How the winner is chosen when the turn limit is reached
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Tiebreak {
+    /// The game is drawn
+    None,
+    /// The player with the greater income wins
+    Income,
+    /// The player whose pieces cost the most in total wins
+    Material,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Ongoing,
+    Won(Player),
+    Draw(DrawReason),
+}
+
+impl GameStatus {
+    #[must_use]
+    pub const fn is_over(self) -> bool {
+        !matches!(self, Self::Ongoing)
+    }
+}
+
+impl Display for GameStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ongoing => write!(f, "the game is ongoing"),
+            Self::Won(player) => write!(f, "{player} won"),
+            Self::Draw(DrawReason::Simultaneous) => {
+                write!(f, "drawn as both players met a victory condition at once")
+            }
+            Self::Draw(DrawReason::TurnLimit) => write!(f, "drawn at the turn limit"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DrawReason {
+    /// Both players met a victory condition at once
+    Simultaneous,
+    /// The turn limit was reached without a tiebreak deciding a winner
+    TurnLimit,
+}
+
+impl Game {
+    #[must_use]
+    pub const fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    #[must_use]
+    pub fn victory_conditions(&self) -> &VictoryConditions {
+        &self.ruleset.victory_conditions
+    }
+
+    /// Returns how many consecutive turns `player` has ended occupying every objective tile
+    #[must_use]
+    pub fn objective_turns(&self, player: Player) -> u32 {
+        self.objective_turns[player]
+    }
+
+    /// Records which players have fielded pieces and works out whether the game has ended
+    pub(crate) fn update_status(&mut self) {
+        for player in [Player::P1, Player::P2] {
+            if self.pieces_of(player).next().is_some() {
+                self.has_fielded[player] = true;
+            }
+        }
+
+        self.status = self.evaluate_status();
+    }
+
+    /// Works out the status of the game from its current state
+    pub(crate) fn evaluate_status(&self) -> GameStatus {
+        match [self.has_won(Player::P1), self.has_won(Player::P2)] {
+            [true, true] => GameStatus::Draw(DrawReason::Simultaneous),
+            [true, false] => GameStatus::Won(Player::P1),
+            [false, true] => GameStatus::Won(Player::P2),
+            [false, false] => self.turn_limit_status(),
+        }
+    }
+
+    /// Updates the objective count of the current player at the end of their turn
+    pub(crate) fn hold_objectives(&mut self) {
+        let Some(objectives) = &self.ruleset.victory_conditions.objectives else {
+            return;
+        };
+        let held = !objectives.tiles.is_empty()
+            && objectives.tiles.iter().all(|coord| {
+                self.board
+                    .get(*coord)
+                    .and_then(|tile| tile.piece_option)
+                    .is_some_and(|piece| piece.owner() == self.current_player)
+            });
+        let turns = &mut self.objective_turns[self.current_player];
+
+        *turns = if held { *turns + 1 } else { 0 };
+    }
+
+    fn has_won(&self, player: Player) -> bool {
+        let conditions = &self.ruleset.victory_conditions;
+
+        (conditions.elimination && self.is_eliminated(-player))
+            || (conditions.border_capture && self.borders_captured(-player))
+            || conditions
+                .money_target
+                .is_some_and(|target| self.money[player] >= target)
+            || conditions
+                .objectives
+                .as_ref()
+                .is_some_and(|objectives| self.objective_turns[player] >= objectives.turns)
+    }
+
+    fn pieces_of(&self, player: Player) -> impl Iterator<Item = Piece> + '_ {
+        self.board
+            .tiles()
+            .filter_map(|tile| tile.piece_option)
+            .filter(move |piece| piece.owner() == player)
+    }
+
+    fn is_eliminated(&self, player: Player) -> bool {
+        self.has_fielded[player] && self.pieces_of(player).next().is_none()
+    }
+
+    /// Returns whether `player` has border tiles and every one is occupied by an enemy piece
+    fn borders_captured(&self, player: Player) -> bool {
+        let mut borders = self
+            .board
+            .tiles()
+            .filter(|tile| tile.kind == TileType::Border(player))
+            .peekable();
+
+        borders.peek().is_some()
+            && borders.all(|tile| {
+                tile.piece_option
+                    .is_some_and(|piece| piece.owner() != player)
+            })
+    }
+
+    fn turn_limit_status(&self) -> GameStatus {
+        let Some(turn_limit) = self.ruleset.victory_conditions.turn_limit else {
+            return GameStatus::Ongoing;
+        };
+
+        if self.turn <= turn_limit.turns {
+            return GameStatus::Ongoing;
+        }
+
+        let scores = match turn_limit.tiebreak {
+            Tiebreak::None => return GameStatus::Draw(DrawReason::TurnLimit),
+            Tiebreak::Income => [self.income(Player::P1), self.income(Player::P2)].map(u32::from),
+            Tiebreak::Material => [Player::P1, Player::P2].map(|player| {
+                self.pieces_of(player)
+                    .map(|piece| u32::from(self.ruleset.units[piece.kind()].cost))
+                    .sum()
+            }),
+        };
+
+        match scores[0].cmp(&scores[1]) {
+            std::cmp::Ordering::Greater => GameStatus::Won(Player::P1),
+            std::cmp::Ordering::Less => GameStatus::Won(Player::P2),
+            std::cmp::Ordering::Equal => GameStatus::Draw(DrawReason::TurnLimit),
+        }
+    }
+}
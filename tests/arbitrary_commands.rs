@@ -0,0 +1,315 @@
+//! Throws arbitrary commands and queries at randomly generated games, checking that nothing
+//! panics and that rejected commands leave the game unchanged.
+
+use convoy::{
+    Game,
+    actions::{BattleActor, Command},
+    battle::ActorOption,
+    board::Board,
+    builder::GameBuilder,
+    coordinates::{PieceCoord, TileCoord},
+    history::UndoPolicy,
+    piece::PieceType,
+    player::Player,
+    ruleset::{DEFAULT_RULESET, Ruleset},
+};
+
+const SEEDS: u64 = 100;
+const STEPS: usize = 300;
+/// The largest generated board, kept smaller than [`FOREIGN_SIZE`] so that some coords fall
+/// outside of it
+const MAX_SIZE: u64 = 8;
+const FOREIGN_SIZE: u8 = 12;
+/// The abilities of the default units replaced by ones that only let them initiate battles
+/// or support them by moving in
+const NARROW_ABILITIES: [(&str, &str); 3] = [
+    (
+        "initiate defend support move-to-attack\n",
+        "initiate defend\n",
+    ),
+    (
+        "initiate defend support move-to-attack move-to-defend self-sufficient",
+        "initiate move-to-attack move-to-defend self-sufficient",
+    ),
+    ("support support-freely", "initiate support-freely"),
+];
+
+/// A xorshift generator, so failures can be reproduced from their seed
+struct Rng(u64);
+
+impl Rng {
+    const fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    const fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    const fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    const fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        let index = usize::try_from(self.below(items.len() as u64)).expect("index fits in usize");
+
+        items[index]
+    }
+
+    fn small(&mut self, bound: u8) -> u8 {
+        u8::try_from(self.below(u64::from(bound))).expect("bound fits in u8")
+    }
+}
+
+/// Produces coords for a board larger than any generated game, with a piece on every tile, so
+/// that handles can point anywhere, and piece types whether or not they are defined
+struct Foreign {
+    board: Board,
+    piece_types: Vec<PieceType>,
+}
+
+impl Foreign {
+    fn new() -> Self {
+        let rank = ".".repeat(usize::from(FOREIGN_SIZE));
+        let overlay = "I".repeat(usize::from(FOREIGN_SIZE));
+        let text = format!(
+            "{}\n{}",
+            format!("{rank}\n").repeat(usize::from(FOREIGN_SIZE)),
+            format!("{overlay}\n").repeat(usize::from(FOREIGN_SIZE))
+        );
+
+        Self {
+            board: text.parse().expect("foreign board text is valid"),
+            piece_types: ('A'..='Z').filter_map(PieceType::from_symbol).collect(),
+        }
+    }
+
+    fn tile(&self, rng: &mut Rng) -> TileCoord {
+        TileCoord::new(
+            rng.small(FOREIGN_SIZE),
+            rng.small(FOREIGN_SIZE),
+            &self.board,
+        )
+        .expect("every foreign coord is a tile")
+    }
+
+    fn piece(&self, rng: &mut Rng) -> PieceCoord {
+        PieceCoord::new(
+            rng.small(FOREIGN_SIZE),
+            rng.small(FOREIGN_SIZE),
+            &self.board,
+        )
+        .expect("every foreign coord holds a piece")
+    }
+
+    fn actor(&self, rng: &mut Rng) -> BattleActor {
+        if rng.chance(1, 2) {
+            BattleActor::Static {
+                coord: self.piece(rng),
+            }
+        } else {
+            BattleActor::Moving {
+                from: self.piece(rng),
+                to: self.tile(rng),
+            }
+        }
+    }
+
+    fn actors(&self, rng: &mut Rng) -> Vec<BattleActor> {
+        (0..rng.below(4)).map(|_| self.actor(rng)).collect()
+    }
+
+    fn command(&self, rng: &mut Rng) -> Command {
+        match rng.below(5) {
+            0 => Command::Move {
+                from: self.piece(rng),
+                to: self.tile(rng),
+            },
+            1 => Command::Recruit {
+                piece_type: rng.pick(&self.piece_types),
+                coord: self.tile(rng),
+            },
+            2 => Command::Battle {
+                target: self.piece(rng),
+                initiator: self.actor(rng),
+                attack_supporters: self.actors(rng),
+            },
+            3 => Command::Defend {
+                target_is_defending: rng.chance(1, 2),
+                defence_supporters: self.actors(rng),
+            },
+            _ => Command::EndTurn,
+        }
+    }
+}
+
+fn random_game(rng: &mut Rng, ruleset: &Ruleset) -> Game {
+    let ranks = rng.below(MAX_SIZE) + 1;
+    let files = rng.below(MAX_SIZE) + 1;
+    let mut tiles = String::new();
+    let mut overlay = String::new();
+
+    for _ in 0..ranks {
+        for _ in 0..files {
+            let tile = rng.pick(&[
+                '.', '.', '.', 't', 'c', '1', '2', 'f', 'm', 'r', '=', '~', '#',
+            ]);
+
+            tiles.push(tile);
+            overlay.push(if tile == '#' || rng.chance(2, 3) {
+                '.'
+            } else {
+                rng.pick(&['A', 'C', 'I', 'R', 'a', 'c', 'i', 'r'])
+            });
+        }
+
+        tiles.push('\n');
+        overlay.push('\n');
+    }
+
+    let board: Board = format!("{tiles}\n{overlay}")
+        .parse()
+        .expect("generated board text is valid");
+    let undo_policy = if rng.chance(1, 2) {
+        UndoPolicy::CASUAL
+    } else {
+        UndoPolicy::COMPETITIVE
+    };
+
+    GameBuilder::new(board)
+        .ruleset(ruleset.clone())
+        .current_player(rng.pick(&[Player::P1, Player::P2]))
+        .money(Player::P1, rng.small(10))
+        .money(Player::P2, rng.small(10))
+        .undo_policy(undo_policy)
+        .build()
+        .expect("generated pieces are on tiles")
+}
+
+/// Exercises the queries that take coords, which must never panic whatever they are given
+fn query(game: &Game, foreign: &Foreign, rng: &mut Rng) {
+    let piece = foreign.piece(rng);
+    let tile = foreign.tile(rng);
+
+    let _ = game.reachable_tiles(piece);
+    let _ = game.path(piece, tile);
+    let _ = game.is_supplied(piece);
+    let _ = game.legal_commands_for(piece);
+    let _ = game.battle_options(piece);
+    let _ = game.can_do_move(piece, tile);
+    let _ = game.can_do_recruit(rng.pick(&foreign.piece_types), tile);
+    let _ = game.preview_battle(&foreign.command(rng));
+}
+
+fn run(seed: u64, foreign: &Foreign) {
+    let mut rng = Rng::new(seed);
+    let mut game = random_game(&mut rng, &Ruleset::default());
+
+    for step in 0..STEPS {
+        query(&game, foreign, &mut rng);
+
+        let legal = game.legal_commands();
+        let is_legal = !legal.is_empty() && rng.chance(1, 2);
+        let command = if is_legal {
+            legal[usize::try_from(rng.below(legal.len() as u64)).expect("index fits in usize")]
+                .clone()
+        } else {
+            foreign.command(&mut rng)
+        };
+        let before = game.clone();
+
+        match game.do_command(&command) {
+            Ok(_) => {}
+            Err(error) if is_legal => {
+                panic!("seed {seed} step {step}: legal {command:?} was rejected: {error}")
+            }
+            Err(_) => assert_eq!(
+                game, before,
+                "seed {seed} step {step}: rejected {command:?} changed the game"
+            ),
+        }
+
+        match rng.below(10) {
+            0 => {
+                game.undo();
+            }
+            1 => {
+                game.redo();
+            }
+            _ => {}
+        }
+
+        if step % 50 == 0 {
+            let mut save = vec![];
+
+            game.save(&mut save).expect("saving to memory succeeds");
+            Game::load(save.as_slice())
+                .unwrap_or_else(|error| panic!("seed {seed} step {step}: reload failed: {error}"));
+        }
+    }
+}
+
+#[test]
+fn arbitrary_commands_never_panic() {
+    let foreign = Foreign::new();
+
+    for seed in 0..SEEDS {
+        run(seed, &foreign);
+    }
+}
+
+#[test]
+fn legal_commands_are_accepted_under_narrow_abilities() {
+    let text = NARROW_ABILITIES
+        .iter()
+        .fold(DEFAULT_RULESET.to_owned(), |text, (from, to)| {
+            assert!(text.contains(from), "the default ruleset has {from:?}");
+            text.replace(from, to)
+        });
+    let ruleset: Ruleset = text.parse().expect("the narrowed ruleset is valid");
+
+    for seed in 0..SEEDS / 4 {
+        let mut rng = Rng::new(seed);
+        let mut game = random_game(&mut rng, &ruleset);
+
+        for step in 0..STEPS / 6 {
+            let legal = game.legal_commands();
+
+            for command in &legal {
+                if let Err(error) = game.clone().do_command(command) {
+                    panic!("seed {seed} step {step}: legal {command:?} was rejected: {error}");
+                }
+
+                if let Command::Battle { target, .. } = command {
+                    let options = game
+                        .battle_options(*target)
+                        .expect("listed battles have options");
+
+                    for initiator in options.initiators.iter().flat_map(ActorOption::actors) {
+                        assert!(
+                            game.can_do_battle(*target, initiator, &[]),
+                            "seed {seed} step {step}: optional {initiator:?} was rejected"
+                        );
+                    }
+                }
+            }
+
+            if legal.is_empty() {
+                break;
+            }
+
+            let index =
+                usize::try_from(rng.below(legal.len() as u64)).expect("index fits in usize");
+
+            game.do_command(&legal[index])
+                .expect("legal commands are accepted");
+        }
+    }
+}
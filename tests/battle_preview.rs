@@ -0,0 +1,159 @@
+pub mod common;
+
+use common::{builder, piece, tile};
+use convoy::{
+    Game,
+    actions::{BattleActor, Command},
+    battle::BattleReport,
+    ruleset::Ruleset,
+};
+
+/// A player 1 infantry attacking a player 2 infantry, with two player 2 recons that can
+/// support it by moving in, the far one only onto the town beside it
+const BOARD: &str = "\
+1......
+....~~~
+...t...
+...~~~~
+......2
+
+.......
+...r...
+.Ii...r
+.......
+.......
+";
+
+/// A player 1 infantry on a city attacking a player 2 infantry on the second town, supported
+/// by an infantry on the first town, while a player 2 recon on a city can support the defence
+const TERRAIN: &str = "\
+1.c..
+.tt.c
+..c.2
+
+..I..
+.Ii..
+..r..
+";
+
+fn game(defence_supporter_terrain: bool) -> Game {
+    builder(BOARD)
+        .ruleset(Ruleset {
+            defence_supporter_terrain,
+            ..Ruleset::default()
+        })
+        .build()
+        .expect("pieces are on tiles")
+}
+
+fn declaration(game: &Game) -> Command {
+    Command::Battle {
+        target: piece(game, 2, 2),
+        initiator: BattleActor::Static {
+            coord: piece(game, 2, 1),
+        },
+        attack_supporters: vec![],
+    }
+}
+
+#[test]
+fn the_best_response_leaves_the_town_to_the_supporter_that_can_only_reach_it() {
+    let game = game(true);
+    let preview = game
+        .preview_battle(&declaration(&game))
+        .expect("the infantry can attack");
+    let expected = Command::Defend {
+        target_is_defending: true,
+        defence_supporters: vec![
+            BattleActor::Moving {
+                from: piece(&game, 1, 3),
+                to: tile(&game, 1, 2),
+            },
+            BattleActor::Moving {
+                from: piece(&game, 2, 6),
+                to: tile(&game, 2, 3),
+            },
+        ],
+    };
+
+    assert_eq!(preview.best_response, expected);
+    assert_eq!(preview.best_report.defence_power, 2 + 1 + 1 + 1);
+    assert_eq!(
+        game.preview_battle(&declaration(&game))
+            .expect("the infantry can attack"),
+        preview
+    );
+}
+
+#[test]
+fn every_supporter_joins_the_best_response_without_terrain_bonuses() {
+    let game = game(false);
+    let preview = game
+        .preview_battle(&declaration(&game))
+        .expect("the infantry can attack");
+    let Command::Defend {
+        defence_supporters, ..
+    } = preview.best_response
+    else {
+        panic!("the best response is a defence");
+    };
+
+    assert_eq!(defence_supporters.len(), 2);
+    assert_eq!(preview.best_report.defence_power, 2 + 1 + 1);
+}
+
+/// Fights a battle on [`TERRAIN`] with the target's town replaced by a tile of
+/// `target_symbol`, returning its report
+fn terrain_report(target_symbol: char, target_is_defending: bool) -> BattleReport {
+    let mut game = common::game(&TERRAIN.replacen("tt", &format!("t{target_symbol}"), 1));
+    let target = piece(&game, 1, 2);
+    let declaration = Command::Battle {
+        target,
+        initiator: BattleActor::Static {
+            coord: piece(&game, 0, 2),
+        },
+        attack_supporters: vec![BattleActor::Static {
+            coord: piece(&game, 1, 1),
+        }],
+    };
+    let defence = Command::Defend {
+        target_is_defending,
+        defence_supporters: vec![BattleActor::Static {
+            coord: piece(&game, 2, 2),
+        }],
+    };
+
+    game.do_command(&declaration)
+        .expect("the infantry can attack");
+    game.do_command(&defence)
+        .expect("the recon can support")
+        .expect("the defence resolves the battle")
+}
+
+/// Returns the terrain bonus of each participant in `report`, in order
+fn terrain_bonuses(report: &BattleReport) -> Vec<u8> {
+    report
+        .participants
+        .iter()
+        .map(|participant| participant.terrain_bonus)
+        .collect()
+}
+
+#[test]
+fn only_a_defending_target_gains_the_defence_bonus_of_its_tile() {
+    let town = terrain_report('t', true);
+    let city = terrain_report('c', true);
+    let undefended = terrain_report('c', false);
+
+    assert_eq!(terrain_bonuses(&town), [0, 0, 1, 0]);
+    assert_eq!(terrain_bonuses(&city), [0, 0, 2, 0]);
+    assert_eq!(terrain_bonuses(&undefended), [0, 0, 0]);
+    assert_eq!(town.participants[2].coord, town.target);
+    assert_eq!(city.defence_power, 2 + 2 + 1);
+    assert!(
+        undefended
+            .participants
+            .iter()
+            .all(|participant| participant.coord != undefended.target)
+    );
+}
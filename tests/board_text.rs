@@ -0,0 +1,110 @@
+pub mod common;
+
+use common::{DUEL, builder};
+use convoy::{
+    board::{Board, BoardError},
+    builder::BuildError,
+    coordinates::Coord,
+    piece::{Piece, PieceType},
+    player::Player,
+};
+
+/// Every tile symbol, with holes and pieces of both players
+const VARIED: &str = "\
+1.tc#
+fmr=~
+#..#2
+
+I.r..
+.A.C.
+.R..i
+";
+
+fn parsed(text: &str) -> Result<Board, BoardError> {
+    text.parse()
+}
+
+#[test]
+fn boards_round_trip_through_text() {
+    let board = parsed(VARIED).expect("the board text is valid");
+
+    assert_eq!(board.to_string(), VARIED);
+    assert_eq!(parsed(&board.to_string()), Ok(board));
+}
+
+#[test]
+fn boards_without_pieces_omit_the_overlay() {
+    let board = parsed("1#\n.2\n").expect("the board text is valid");
+
+    assert_eq!(board.to_string(), "1#\n.2\n");
+    assert_eq!(parsed("1#\n.2\n\n..\n..\n"), Ok(board));
+}
+
+#[test]
+fn malformed_board_text_is_rejected() {
+    assert_eq!(parsed("1..\n..\n"), Err(BoardError::RaggedRank { rank: 1 }));
+    assert_eq!(
+        parsed("1.\n.x\n"),
+        Err(BoardError::UnknownSymbol {
+            rank: 1,
+            file: 1,
+            symbol: 'x',
+        })
+    );
+    assert_eq!(
+        parsed("1.\n.2\n\n.3\n..\n"),
+        Err(BoardError::UnknownSymbol {
+            rank: 0,
+            file: 1,
+            symbol: '3',
+        })
+    );
+    assert_eq!(parsed("1.\n.2\n\n..\n"), Err(BoardError::OverlayMismatch));
+    assert_eq!(
+        parsed("1.\n.2\n\n..\n...\n"),
+        Err(BoardError::OverlayMismatch)
+    );
+    assert_eq!(
+        parsed("1#\n.2\n\n.I\n..\n"),
+        Err(BoardError::PieceOnHole { rank: 0, file: 1 })
+    );
+}
+
+#[test]
+fn unplayable_positions_fail_to_build() {
+    let infantry = Piece::new(PieceType::INFANTRY, Player::P1);
+
+    assert_eq!(
+        builder("1#\n.2\n")
+            .piece(Coord::new(0, 1), infantry)
+            .build()
+            .err(),
+        Some(BuildError::MissingTile(Coord::new(0, 1)))
+    );
+    assert_eq!(
+        builder("1#\n.2\n")
+            .piece(Coord::new(2, 0), infantry)
+            .build()
+            .err(),
+        Some(BuildError::MissingTile(Coord::new(2, 0)))
+    );
+    assert_eq!(
+        builder(DUEL)
+            .piece(Coord::new(0, 1), infantry)
+            .build()
+            .err(),
+        Some(BuildError::TileOccupied(Coord::new(0, 1)))
+    );
+    assert_eq!(
+        builder("1t\n.2\n")
+            .capture(Coord::new(0, 1), Player::P2)
+            .capture(Coord::new(0, 0), Player::P2)
+            .build()
+            .err(),
+        Some(BuildError::NotCapturable(Coord::new(0, 0)))
+    );
+    assert_eq!(
+        builder("1.\n.2\n\n..\nZ.\n").build().err(),
+        Some(BuildError::UnknownPieceType(Coord::new(1, 0)))
+    );
+}
@@ -0,0 +1,84 @@
+//! Fixtures shared by the integration tests
+
+use convoy::{
+    Game,
+    actions::BattleActor,
+    board::Board,
+    builder::GameBuilder,
+    coordinates::{PieceCoord, TileCoord},
+    piece::PieceType,
+};
+
+/// Each player's border and infantry, two tiles apart
+pub const DUEL: &str = "\
+1....
+....2
+
+.I...
+..i..
+";
+
+/// Returns a builder for a game on the board described by `text`
+///
+/// # Panics
+///
+/// Panics if `text` is not a valid board.
+#[must_use]
+pub fn builder(text: &str) -> GameBuilder {
+    let board: Board = text.parse().expect("board text is valid");
+
+    GameBuilder::new(board)
+}
+
+/// Creates a game with the standard rules on the board described by `text`
+///
+/// # Panics
+///
+/// Panics if `text` is not a valid board.
+#[must_use]
+pub fn game(text: &str) -> Game {
+    builder(text).build().expect("pieces are on tiles")
+}
+
+/// # Panics
+///
+/// Panics if no piece is at the coord.
+#[must_use]
+pub fn piece(game: &Game, rank: u8, file: u8) -> PieceCoord {
+    PieceCoord::new(rank, file, game.board()).expect("a piece is at the coord")
+}
+
+/// # Panics
+///
+/// Panics if no tile is at the coord.
+#[must_use]
+pub fn tile(game: &Game, rank: u8, file: u8) -> TileCoord {
+    TileCoord::new(rank, file, game.board()).expect("a tile is at the coord")
+}
+
+/// Has player 1 recruit infantry on their border in a [`DUEL`], then ends a turn each
+///
+/// # Panics
+///
+/// Panics if any of the commands is rejected.
+pub fn play_opening(game: &mut Game) {
+    game.do_recruit(PieceType::INFANTRY, tile(game, 0, 0))
+        .expect("player 1 can afford infantry");
+    game.do_end_turn().expect("player 1 can end their turn");
+    game.do_end_turn().expect("player 2 can end their turn");
+}
+
+/// Moves the player 1 infantry of a [`DUEL`] beside the player 2 infantry and attacks it
+///
+/// # Panics
+///
+/// Panics if the battle is rejected.
+pub fn declare_battle(game: &mut Game) {
+    let initiator = BattleActor::Moving {
+        from: piece(game, 0, 1),
+        to: tile(game, 0, 2),
+    };
+
+    game.do_battle(piece(game, 1, 2), initiator, &[])
+        .expect("the infantry can move in to attack");
+}
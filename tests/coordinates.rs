@@ -0,0 +1,91 @@
+use convoy::coordinates::{Coord, Coordinate};
+
+/// The coords at the corners of the largest possible coordinate space
+const CORNERS: [Coord; 4] = [
+    Coord::new(0, 0),
+    Coord::new(0, u8::MAX),
+    Coord::new(u8::MAX, 0),
+    Coord::new(u8::MAX, u8::MAX),
+];
+
+fn coords(pairs: &[(u8, u8)]) -> Vec<Coord> {
+    pairs
+        .iter()
+        .map(|(rank, file)| Coord::new(*rank, *file))
+        .collect()
+}
+
+/// Returns every coord whose distance from `center` is accepted, in rank then file order
+fn every_coord(center: Coord, accept: impl Fn(u16) -> bool) -> Vec<Coord> {
+    CORNERS[0]
+        .rectangle(CORNERS[3])
+        .filter(|coord| accept(coord.distance(center)))
+        .collect()
+}
+
+#[test]
+fn rings_are_clipped_at_the_edges() {
+    assert_eq!(
+        Coord::new(0, 0).ring(2).collect::<Vec<_>>(),
+        coords(&[(0, 2), (1, 1), (2, 0)])
+    );
+    assert_eq!(
+        Coord::new(u8::MAX, u8::MAX).ring(1).collect::<Vec<_>>(),
+        coords(&[(254, 255), (255, 254)])
+    );
+    assert_eq!(
+        Coord::new(0, 0).ring(0).collect::<Vec<_>>(),
+        coords(&[(0, 0)])
+    );
+    assert_eq!(
+        Coord::new(0, 0).ring(510).collect::<Vec<_>>(),
+        coords(&[(255, 255)])
+    );
+    assert_eq!(Coord::new(0, 0).ring(511).count(), 0);
+}
+
+#[test]
+fn areas_are_clipped_at_the_edges() {
+    assert_eq!(
+        Coord::new(0, 0).within(1).collect::<Vec<_>>(),
+        coords(&[(0, 0), (0, 1), (1, 0)])
+    );
+    assert_eq!(
+        Coord::new(u8::MAX, u8::MAX).within(1).collect::<Vec<_>>(),
+        coords(&[(254, 255), (255, 254), (255, 255)])
+    );
+    assert_eq!(Coord::new(0, u8::MAX).within(u16::MAX).count(), 256 * 256);
+}
+
+#[test]
+fn rings_and_areas_at_the_corners_match_the_distances() {
+    for center in CORNERS {
+        for distance in 0..4 {
+            assert_eq!(
+                center.ring(distance).collect::<Vec<_>>(),
+                every_coord(center, |found| found == distance)
+            );
+            assert_eq!(
+                center.within(distance).collect::<Vec<_>>(),
+                every_coord(center, |found| found <= distance)
+            );
+        }
+    }
+}
+
+#[test]
+fn rectangles_reach_the_edges_from_either_corner() {
+    assert_eq!(
+        Coord::new(u8::MAX, 0)
+            .rectangle(Coord::new(254, 1))
+            .collect::<Vec<_>>(),
+        coords(&[(254, 0), (254, 1), (255, 0), (255, 1)])
+    );
+    assert_eq!(
+        Coord::new(0, u8::MAX)
+            .rectangle(Coord::new(0, u8::MAX))
+            .collect::<Vec<_>>(),
+        coords(&[(0, 255)])
+    );
+    assert_eq!(CORNERS[3].rectangle(CORNERS[0]).count(), 256 * 256);
+}
@@ -0,0 +1,196 @@
+use convoy::{
+    Game,
+    actions::BattleActor,
+    board::{Board, BoardError},
+    builder::GameBuilder,
+    coordinates::{Coord, Coordinate, PieceCoord, TileCoord},
+    error::{BattleRole, CommandError},
+    piece::{Piece, PieceType},
+    player::Player,
+    tile::{Tile, TileType},
+};
+
+/// The most ranks and files a board can have
+const MAX: u8 = u8::MAX;
+
+const fn ready(kind: PieceType, owner: Player) -> Piece {
+    let mut piece = Piece::new(kind, owner);
+
+    piece.actions.recruited = false;
+    piece
+}
+
+#[test]
+fn largest_board_addresses_every_corner() {
+    let corners = [
+        Coord::new(0, 0),
+        Coord::new(0, MAX - 1),
+        Coord::new(MAX - 1, 0),
+        Coord::new(MAX - 1, MAX - 1),
+    ];
+    let builder = corners.iter().fold(
+        GameBuilder::new(Board::filled(MAX, MAX, TileType::Empty)),
+        |builder, coord| builder.piece(*coord, ready(PieceType::RECON, Player::P1)),
+    );
+    let game = builder.build().expect("every corner is a tile");
+    let board = game.board();
+
+    assert_eq!(board.tiles().count(), usize::from(MAX) * usize::from(MAX));
+    assert_eq!(board.piece_coords().collect::<Vec<_>>(), corners);
+    assert!(board.get(Coord::new(MAX - 1, MAX - 2)).is_some());
+    assert!(
+        board
+            .get(Coord::new(MAX - 1, MAX - 2))
+            .expect("tile exists")
+            .piece_option
+            .is_none()
+    );
+}
+
+#[test]
+fn tiles_past_the_edges_do_not_exist() {
+    let board = Board::filled(MAX, MAX, TileType::Empty);
+
+    assert_eq!(
+        board.new_coord(MAX - 1, MAX - 1),
+        Some(Coord::new(MAX - 1, MAX - 1))
+    );
+    assert_eq!(board.new_coord(MAX, 0), None);
+    assert_eq!(board.new_coord(0, MAX), None);
+    assert!(!board.coord_in_bounds(Coord::new(MAX, MAX)));
+    assert!(board.get(Coord::new(MAX, MAX)).is_none());
+
+    let small = Board::filled(3, 4, TileType::Empty);
+
+    assert_eq!(small.new_coord(3, 0), None);
+    assert_eq!(small.new_coord(0, 4), None);
+    assert!(small.new_coord(2, 3).is_some());
+}
+
+#[test]
+fn distant_ranks_do_not_alias() {
+    let game = GameBuilder::new(Board::filled(100, 100, TileType::Empty))
+        .piece(Coord::new(99, 0), ready(PieceType::INFANTRY, Player::P1))
+        .build()
+        .expect("the piece is on a tile");
+
+    for rank in 0..100 {
+        for file in 0..100 {
+            let has_piece = game
+                .board()
+                .get(Coord::new(rank, file))
+                .expect("every coord is a tile")
+                .piece_option
+                .is_some();
+
+            assert_eq!(has_piece, (rank, file) == (99, 0));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "outside a board of 2 ranks and 2 files")]
+fn coords_from_larger_boards_do_not_alias_when_indexing() {
+    let large = Board::filled(3, 3, TileType::Empty);
+    let small = Board::filled(2, 2, TileType::Empty);
+    let coord = TileCoord::new(0, 2, &large).expect("the tile is on the larger board");
+
+    assert!(small.get(coord.as_coord()).is_none());
+
+    let _ = small[coord];
+}
+
+#[test]
+fn distance_spans_the_largest_board() {
+    let origin = Coord::new(0, 0);
+    let corner = Coord::new(MAX, MAX);
+
+    assert_eq!(origin.distance(corner), 2 * u16::from(MAX));
+    assert_eq!(corner.distance(origin), 510);
+}
+
+#[test]
+fn oversized_boards_are_rejected() {
+    let ranks = vec![vec![Some(Tile::new(TileType::Empty))]; usize::from(MAX) + 1];
+
+    assert_eq!(Board::from_tiles(ranks), Err(BoardError::TooLarge));
+
+    let files = vec![vec![Some(Tile::new(TileType::Empty)); usize::from(MAX) + 1]];
+
+    assert_eq!(Board::from_tiles(files), Err(BoardError::TooLarge));
+
+    let text = format!("{}\n", ".".repeat(usize::from(MAX) + 1));
+
+    assert_eq!(text.parse::<Board>(), Err(BoardError::TooLarge));
+}
+
+#[test]
+fn pieces_move_along_the_far_edges() {
+    let mut game = GameBuilder::new(Board::filled(MAX, MAX, TileType::Empty))
+        .piece(
+            Coord::new(MAX - 1, MAX - 1),
+            ready(PieceType::RECON, Player::P1),
+        )
+        .build()
+        .expect("the corner is a tile");
+    let board = game.board().clone();
+    let from = PieceCoord::new(MAX - 1, MAX - 1, &board).expect("the recon is in the corner");
+    let reachable = game.reachable_tiles(from);
+
+    assert_eq!(reachable.len(), 9);
+    assert!(
+        reachable
+            .iter()
+            .all(|to| to.rank() < MAX && to.file() < MAX && to.distance(from) <= 3)
+    );
+
+    let to = TileCoord::new(MAX - 3, MAX - 2, &board).expect("the tile exists");
+
+    game.do_move(from, to)
+        .expect("the recon can reach the tile");
+    assert!(game.board()[to.as_coord()].piece_option.is_some());
+}
+
+#[test]
+fn far_apart_pieces_are_out_of_range() {
+    let game = GameBuilder::new(Board::filled(MAX, MAX, TileType::Empty))
+        .piece(Coord::new(0, 0), ready(PieceType::INFANTRY, Player::P1))
+        .piece(
+            Coord::new(MAX - 1, MAX - 1),
+            ready(PieceType::INFANTRY, Player::P2),
+        )
+        .build()
+        .expect("the corners are tiles");
+    let board = game.board();
+    let initiator = PieceCoord::new(0, 0, board).expect("the attacker is in the corner");
+    let target = PieceCoord::new(MAX - 1, MAX - 1, board).expect("the target is in the corner");
+
+    assert_eq!(
+        game.check_battle(target, BattleActor::Static { coord: initiator }, &[]),
+        Err(CommandError::InvalidActor {
+            role: BattleRole::Initiator,
+            reason: Box::new(CommandError::OutOfRange {
+                distance: 2 * u16::from(MAX - 1),
+                range: 1..2,
+            }),
+        })
+    );
+}
+
+#[test]
+fn largest_board_survives_saving() {
+    let game = GameBuilder::new(Board::filled(MAX, MAX, TileType::Empty))
+        .piece(
+            Coord::new(MAX - 1, MAX - 1),
+            ready(PieceType::CONVOY, Player::P2),
+        )
+        .build()
+        .expect("the corner is a tile");
+    let mut save = vec![];
+
+    game.save(&mut save).expect("saving to memory succeeds");
+
+    let loaded = Game::load(save.as_slice()).expect("the save is valid");
+
+    assert_eq!(loaded.board(), game.board());
+}
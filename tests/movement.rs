@@ -0,0 +1,87 @@
+pub mod common;
+
+use common::{game, piece, tile};
+use convoy::coordinates::{Coordinate, TileCoord};
+
+/// A player 1 recon hemmed in by a hole and a player 2 infantry, beside a friendly infantry
+/// it can pass through
+const BOARD: &str = "\
+1....
+.#...
+.....
+....2
+
+.....
+.....
+IR.i.
+.....
+";
+
+fn coords(tiles: &[TileCoord]) -> Vec<(u8, u8)> {
+    tiles
+        .iter()
+        .map(|tile| (tile.rank(), tile.file()))
+        .collect()
+}
+
+#[test]
+fn reachable_tiles_come_out_in_coord_order() {
+    let game = game(BOARD);
+    let recon = piece(&game, 2, 1);
+    let tiles = game.reachable_tiles(recon);
+    let mut sorted = coords(&tiles);
+
+    sorted.sort_unstable();
+
+    assert_eq!(coords(&tiles), sorted);
+
+    for _ in 0..10 {
+        assert_eq!(game.reachable_tiles(recon), tiles);
+        assert_eq!(game.legal_commands(), game.legal_commands());
+    }
+}
+
+#[test]
+fn movement_avoids_holes_and_enemies_but_passes_friends() {
+    let game = game(BOARD);
+    let recon = piece(&game, 2, 1);
+    let tiles = coords(&game.reachable_tiles(recon));
+
+    assert!(!tiles.contains(&(1, 1)));
+    assert!(!tiles.contains(&(2, 3)));
+    assert!(!tiles.contains(&(2, 4)));
+    assert!(tiles.contains(&(1, 0)));
+    assert!(tiles.contains(&(3, 0)));
+    assert_eq!(
+        game.path(recon, tile(&game, 3, 0))
+            .map(|path| coords(&path)),
+        Some(vec![(2, 1), (2, 0), (3, 0)])
+    );
+}
+
+#[test]
+fn paths_only_lead_to_reachable_tiles() {
+    let game = game(BOARD);
+    let recon = piece(&game, 2, 1);
+
+    assert_eq!(game.path(recon, tile(&game, 2, 0)), None);
+    assert_eq!(game.path(recon, tile(&game, 2, 1)), None);
+    assert!(!game.can_do_move(recon, tile(&game, 2, 0)));
+
+    for to in game.reachable_tiles(recon) {
+        assert!(game.path(recon, to).is_some());
+    }
+}
+
+#[test]
+fn rejected_moves_describe_coords_by_rank_and_file() {
+    let mut game = game(BOARD);
+    let error = game
+        .do_move(piece(&game, 2, 3), tile(&game, 2, 4))
+        .expect_err("player 1 cannot move the enemy infantry");
+
+    assert_eq!(
+        error.to_string(),
+        "piece at rank 2, file 3 belongs to player 2"
+    );
+}
@@ -0,0 +1,112 @@
+pub mod common;
+
+use common::{DUEL, builder, declare_battle, game, piece, tile};
+use convoy::{
+    Game,
+    piece::{Piece, PieceId, PieceType},
+    victory::VictoryConditions,
+};
+
+/// Returns the ids of every piece on the board, in board order
+fn ids(game: &Game) -> Vec<PieceId> {
+    game.board()
+        .tiles()
+        .filter_map(|tile| tile.piece_option)
+        .map(Piece::id)
+        .collect()
+}
+
+#[test]
+fn ids_follow_pieces_as_they_move() {
+    let mut game = game(DUEL);
+    let id = game.board()[piece(&game, 0, 1)].id();
+
+    game.do_move(piece(&game, 0, 1), tile(&game, 0, 3))
+        .expect("the infantry can move");
+
+    assert_eq!(game.locate(id), Some(piece(&game, 0, 3)));
+    assert_eq!(game.piece(id).map(Piece::kind), Some(PieceType::INFANTRY));
+}
+
+#[test]
+fn ids_follow_pieces_moving_into_battle() {
+    let mut game = game(DUEL);
+    let attacker = game.board()[piece(&game, 0, 1)].id();
+    let target = game.board()[piece(&game, 1, 2)].id();
+
+    declare_battle(&mut game);
+
+    assert_eq!(game.locate(attacker), Some(piece(&game, 0, 2)));
+
+    game.do_defend(false, &[])
+        .expect("the infantry can decline to defend");
+
+    assert_eq!(game.locate(attacker), Some(piece(&game, 0, 2)));
+    assert_eq!(game.locate(target), None);
+    assert_eq!(game.piece(target), None);
+}
+
+#[test]
+fn recruits_get_fresh_ids_that_are_never_reused() {
+    let mut game = builder(DUEL)
+        .victory_conditions(VictoryConditions {
+            elimination: false,
+            ..VictoryConditions::new()
+        })
+        .build()
+        .expect("pieces are on tiles");
+    let mut seen = ids(&game);
+
+    declare_battle(&mut game);
+    game.do_defend(false, &[])
+        .expect("the infantry can decline to defend");
+    game.do_end_turn().expect("player 1 can end their turn");
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 1, 4))
+        .expect("player 2 can afford infantry");
+
+    let recruit = game.board()[piece(&game, 1, 4)].id();
+
+    assert!(!seen.contains(&recruit));
+
+    seen.push(recruit);
+    game.do_end_turn().expect("player 2 can end their turn");
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 0, 0))
+        .expect("player 1 can afford infantry");
+
+    assert!(!seen.contains(&game.board()[piece(&game, 0, 0)].id()));
+}
+
+#[test]
+fn ids_survive_saving_and_undoing() {
+    let mut game = game(DUEL);
+
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 0, 0))
+        .expect("player 1 can afford infantry");
+
+    let id = game.board()[piece(&game, 0, 1)].id();
+    let mut save = vec![];
+
+    game.save(&mut save).expect("saving to memory succeeds");
+
+    let mut loaded = Game::load(save.as_slice()).expect("the save is valid");
+
+    assert_eq!(ids(&loaded), ids(&game));
+
+    loaded
+        .do_move(piece(&loaded, 0, 1), tile(&loaded, 0, 3))
+        .expect("the infantry can move");
+    loaded.undo();
+
+    assert_eq!(loaded.locate(id), Some(piece(&loaded, 0, 1)));
+    assert_eq!(ids(&loaded), ids(&game));
+
+    game.do_end_turn().expect("player 1 can end their turn");
+    loaded.do_end_turn().expect("player 1 can end their turn");
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 1, 4))
+        .expect("player 2 can afford infantry");
+    loaded
+        .do_recruit(PieceType::INFANTRY, tile(&loaded, 1, 4))
+        .expect("player 2 can afford infantry");
+
+    assert_eq!(ids(&loaded), ids(&game));
+}
@@ -0,0 +1,226 @@
+pub mod common;
+
+use common::{game, piece, tile};
+use convoy::{
+    Game,
+    actions::BattleActor,
+    battle::BattleOutcome,
+    error::{BattleRole, CommandError},
+};
+
+/// A player 1 infantry beside a player 2 infantry, with a player 2 recon, infantry and
+/// artillery out of range of it, all supplied by nearby borders
+const BOARD: &str = "\
+.....
+.....
+1....
+....2
+.....
+
+....a
+.....
+.Ii..
+.....
+..i.r
+";
+
+/// Declares a battle by the player 1 infantry against the player 2 infantry beside it
+fn declare(game: &mut Game) {
+    let target = piece(game, 2, 2);
+    let initiator = BattleActor::Static {
+        coord: piece(game, 2, 1),
+    };
+
+    game.do_battle(target, initiator, &[])
+        .expect("the infantry can attack");
+}
+
+fn rejected_supporter(game: &Game, actor: BattleActor) -> CommandError {
+    match game.check_defend(false, &[actor]) {
+        Err(CommandError::InvalidActor {
+            role: BattleRole::DefenceSupporter(0),
+            reason,
+        }) => *reason,
+        result => panic!("expected the supporter to be rejected, got {result:?}"),
+    }
+}
+
+#[test]
+fn recon_moves_into_range_to_defend() {
+    let mut game = game(BOARD);
+    declare(&mut game);
+
+    let from = piece(&game, 4, 4);
+    let to = tile(&game, 2, 3);
+    let report = game
+        .do_defend(true, &[BattleActor::Moving { from, to }])
+        .expect("the recon can intercept");
+
+    assert_eq!(report.outcome, BattleOutcome::DefenderWins);
+    assert_eq!(report.defence_power, 3);
+    assert!(game.board()[from.as_coord()].piece_option.is_none());
+
+    let recon = game.board()[to.as_coord()]
+        .piece_option
+        .expect("the recon moved to its destination");
+
+    assert!(recon.is_exhausted(&game.units()[recon.kind()]));
+}
+
+#[test]
+fn recon_stays_at_destination_when_attacker_wins() {
+    let mut game = game(BOARD);
+    declare(&mut game);
+
+    let target = piece(&game, 2, 2);
+    let from = piece(&game, 4, 4);
+    let to = tile(&game, 3, 2);
+    let report = game
+        .do_defend(false, &[BattleActor::Moving { from, to }])
+        .expect("the recon can intercept");
+
+    assert_eq!(report.outcome, BattleOutcome::AttackerWins);
+    assert!(game.board()[target.as_coord()].piece_option.is_none());
+    assert!(game.board()[to.as_coord()].piece_option.is_some());
+}
+
+#[test]
+fn other_defenders_cannot_move() {
+    let mut game = game(BOARD);
+    declare(&mut game);
+
+    let infantry = BattleActor::Moving {
+        from: piece(&game, 4, 2),
+        to: tile(&game, 3, 2),
+    };
+    let artillery = BattleActor::Moving {
+        from: piece(&game, 0, 4),
+        to: tile(&game, 0, 2),
+    };
+
+    for actor in [infantry, artillery] {
+        let BattleActor::Moving { from, .. } = actor else {
+            unreachable!("both actors move");
+        };
+
+        assert_eq!(
+            rejected_supporter(&game, actor),
+            CommandError::CannotSupport(from)
+        );
+    }
+}
+
+#[test]
+fn recon_is_limited_by_speed() {
+    let mut game = game(BOARD);
+    declare(&mut game);
+
+    let actor = BattleActor::Moving {
+        from: piece(&game, 4, 4),
+        to: tile(&game, 1, 2),
+    };
+
+    assert_eq!(
+        rejected_supporter(&game, actor),
+        CommandError::OutOfSpeed {
+            distance: 5,
+            speed: 3
+        }
+    );
+}
+
+#[test]
+fn recon_needs_an_unblocked_path() {
+    let mut game = game(
+        "\
+.....
+...#.
+...#.
+...#.
+.....
+
+.....
+.....
+.Ii.r
+.....
+.....
+",
+    );
+    declare(&mut game);
+
+    let to = tile(&game, 1, 2);
+    let actor = BattleActor::Moving {
+        from: piece(&game, 2, 4),
+        to,
+    };
+
+    assert_eq!(rejected_supporter(&game, actor), CommandError::NoPath(to));
+}
+
+#[test]
+fn recon_must_end_in_range() {
+    let mut game = game(BOARD);
+    declare(&mut game);
+
+    let actor = BattleActor::Moving {
+        from: piece(&game, 4, 4),
+        to: tile(&game, 4, 3),
+    };
+
+    assert_eq!(
+        rejected_supporter(&game, actor),
+        CommandError::OutOfRange {
+            distance: 3,
+            range: 1..2
+        }
+    );
+}
+
+#[test]
+fn exhausted_recon_cannot_intercept() {
+    let mut game = game(BOARD);
+    let recon = piece(&game, 4, 4);
+
+    game.do_end_turn().expect("player 1 can end their turn");
+    game.do_move(recon, tile(&game, 4, 3))
+        .expect("the recon can move on its own turn");
+    game.do_end_turn().expect("player 2 can end their turn");
+    declare(&mut game);
+
+    let from = piece(&game, 4, 3);
+
+    assert_eq!(
+        rejected_supporter(
+            &game,
+            BattleActor::Moving {
+                from,
+                to: tile(&game, 3, 2),
+            }
+        ),
+        CommandError::PieceExhausted(from)
+    );
+}
+
+#[test]
+fn battle_options_only_offer_recon_destinations() {
+    let game = game(BOARD);
+    let options = game
+        .battle_options(piece(&game, 2, 2))
+        .expect("the target is an enemy piece");
+
+    let recon = options
+        .defence_supporters
+        .iter()
+        .find(|option| option.coord == piece(&game, 4, 4))
+        .expect("the recon can defend");
+
+    assert!(!recon.in_place);
+    assert!(recon.destinations.contains(&tile(&game, 2, 3)));
+    assert!(
+        options
+            .defence_supporters
+            .iter()
+            .filter(|option| option.coord != recon.coord)
+            .all(|option| option.destinations.is_empty())
+    );
+}
@@ -0,0 +1,87 @@
+pub mod common;
+
+use common::{DUEL, declare_battle, game, play_opening, tile};
+use convoy::{Game, actions::Command, error::CommandError, piece::PieceType, replay::Replay};
+
+fn initial() -> Game {
+    game(DUEL)
+}
+
+/// Plays a recruitment and a turn each, then a battle started by player 1 moving in
+fn play(game: &mut Game) {
+    play_opening(game);
+    declare_battle(game);
+    game.do_defend(true, &[])
+        .expect("the infantry can defend itself");
+}
+
+fn logged_commands(game: &Game) -> Vec<Command> {
+    game.log()
+        .iter()
+        .map(|logged| logged.command.clone())
+        .collect()
+}
+
+#[test]
+fn replaying_the_log_reproduces_the_game() {
+    let mut game = initial();
+
+    play(&mut game);
+
+    let mut replay = Replay::new(initial(), logged_commands(&game));
+
+    replay
+        .seek(usize::MAX)
+        .expect("every logged command was accepted");
+
+    assert!(replay.is_finished());
+    assert_eq!(replay.game(), &game);
+}
+
+#[test]
+fn stepping_back_reproduces_earlier_games() {
+    let mut game = initial();
+
+    play(&mut game);
+
+    let mut replay = Replay::new(initial(), logged_commands(&game));
+    let mut games = vec![replay.game().clone()];
+
+    while replay
+        .step_forward()
+        .expect("every logged command was accepted")
+    {
+        games.push(replay.game().clone());
+    }
+
+    while let Some(expected) = games.pop() {
+        assert_eq!(replay.game(), &expected);
+        assert_eq!(replay.cursor(), games.len());
+        replay
+            .step_back()
+            .expect("earlier commands are accepted again");
+    }
+
+    assert!(
+        !replay
+            .step_back()
+            .expect("stepping back at the start is fine")
+    );
+}
+
+#[test]
+fn rejected_commands_stop_the_cursor_before_them() {
+    let initial = initial();
+    let border = tile(&initial, 0, 0);
+    let recruit = Command::Recruit {
+        piece_type: PieceType::INFANTRY,
+        coord: border,
+    };
+    let mut replay = Replay::new(initial, vec![Command::EndTurn, recruit, Command::EndTurn]);
+
+    assert!(matches!(
+        replay.seek(usize::MAX),
+        Err(CommandError::NotRecruitmentTile(_))
+    ));
+    assert_eq!(replay.cursor(), 1);
+}
@@ -0,0 +1,181 @@
+use convoy::{
+    coordinates::Coord,
+    ruleset::{DEFAULT_RULESET, Ruleset, RulesetError},
+    tile::Terrain,
+    victory::{Objectives, Tiebreak, TurnLimit},
+};
+
+/// A unit that is not part of the standard rules
+const TANK: &str = "
+[unit Tank]
+symbol = T
+speed = 4
+power = 3
+range = 1..3
+cost = 6
+abilities = initiate defend support move-to-attack
+move-costs = forest:2 river:none road:1
+";
+
+/// Returns the default ruleset text with the first occurrence of `from` replaced by `to`
+fn edited(from: &str, to: &str) -> String {
+    assert!(
+        DEFAULT_RULESET.contains(from),
+        "the default ruleset has {from:?}"
+    );
+    DEFAULT_RULESET.replacen(from, to, 1)
+}
+
+/// Returns the one based number of the first line of the default ruleset equal to `line`
+fn line_of(line: &str) -> usize {
+    DEFAULT_RULESET
+        .lines()
+        .position(|found| found == line)
+        .expect("the default ruleset has the line")
+        + 1
+}
+
+fn parsed(text: &str) -> Result<Ruleset, RulesetError> {
+    text.parse()
+}
+
+#[test]
+fn rulesets_round_trip_through_text() {
+    let mut ruleset = parsed(&format!("{DEFAULT_RULESET}{TANK}")).expect("the tank is valid");
+
+    ruleset.base_income = 3;
+    ruleset.supply_radius = 5;
+    ruleset.defence_supporter_terrain = true;
+    ruleset.victory_conditions.money_target = Some(40);
+    ruleset.victory_conditions.objectives = Some(Objectives {
+        tiles: vec![Coord::new(2, 3), Coord::new(0, 255)],
+        turns: 3,
+    });
+    ruleset.victory_conditions.turn_limit = Some(TurnLimit {
+        turns: 60,
+        tiebreak: Tiebreak::Material,
+    });
+    ruleset.tiles[Terrain::Road].income = 1;
+    ruleset.tiles[Terrain::Sea].move_cost = Some(5);
+    ruleset.validate().expect("the ruleset is playable");
+
+    assert_eq!(parsed(&ruleset.to_string()), Ok(ruleset.clone()));
+    assert_eq!(
+        parsed(&Ruleset::default().to_string()),
+        Ok(Ruleset::default())
+    );
+    assert_eq!(ruleset.terrain_stats(Terrain::Road).income, 1);
+}
+
+#[test]
+fn unplayable_rulesets_fail_validation() {
+    let unplayable = |change: fn(&mut Ruleset)| {
+        let mut ruleset = Ruleset::default();
+
+        change(&mut ruleset);
+        ruleset.validate()
+    };
+
+    assert_eq!(
+        unplayable(|ruleset| ruleset.tiles[Terrain::Empty].move_cost = Some(0)),
+        Err(RulesetError::ZeroMoveCost)
+    );
+    assert_eq!(
+        unplayable(|ruleset| {
+            ruleset.starting_money = 10;
+            ruleset.victory_conditions.money_target = Some(10);
+        }),
+        Err(RulesetError::MoneyTargetReached)
+    );
+    assert_eq!(
+        unplayable(|ruleset| {
+            ruleset.victory_conditions.objectives = Some(Objectives {
+                tiles: vec![],
+                turns: 2,
+            });
+        }),
+        Err(RulesetError::InvalidObjectives)
+    );
+    assert_eq!(
+        unplayable(|ruleset| {
+            ruleset.victory_conditions.objectives = Some(Objectives {
+                tiles: vec![Coord::new(0, 0)],
+                turns: 0,
+            });
+        }),
+        Err(RulesetError::InvalidObjectives)
+    );
+    assert_eq!(
+        unplayable(|ruleset| {
+            ruleset.victory_conditions.turn_limit = Some(TurnLimit {
+                turns: 0,
+                tiebreak: Tiebreak::None,
+            });
+        }),
+        Err(RulesetError::ZeroTurnLimit)
+    );
+}
+
+#[test]
+fn unplayable_ruleset_text_is_rejected() {
+    assert_eq!(
+        parsed(&edited("forest:1 mountain:2", "forest:0 mountain:2")),
+        Err(RulesetError::ZeroMoveCost)
+    );
+    assert_eq!(
+        parsed(&edited("money-target = none", "money-target = 0")),
+        Err(RulesetError::MoneyTargetReached)
+    );
+    assert_eq!(
+        parsed(&edited("objectives = none", "objectives = 0 1,1")),
+        Err(RulesetError::InvalidObjectives)
+    );
+}
+
+#[test]
+fn malformed_ruleset_text_is_rejected() {
+    let town = line_of("[tile town]");
+
+    assert_eq!(
+        parsed(&edited(
+            "[tile town]\nincome = 1\n",
+            "[tile town]\nincome = 1\nincome = 2\n"
+        )),
+        Err(RulesetError::DuplicateKey {
+            line: town + 2,
+            key: "income".to_owned(),
+        })
+    );
+    assert_eq!(
+        parsed(&edited("[tile town]", "[tile lava]")),
+        Err(RulesetError::UnknownSection { line: town })
+    );
+    assert_eq!(
+        parsed(&edited("[tile town]", "[tile  town]")),
+        Err(RulesetError::UnknownSection { line: town })
+    );
+    assert_eq!(
+        parsed(&edited("[tile town]\nincome = 1\n", "[tile town]\n")),
+        Err(RulesetError::MissingKey {
+            section: "tile town".to_owned(),
+            key: "income",
+        })
+    );
+    assert_eq!(
+        parsed(&edited(
+            "[tile town]\nincome = 1\ndefence = 1\nmove-cost = 1\n",
+            ""
+        )),
+        Err(RulesetError::MissingSection("tile town".to_owned()))
+    );
+    assert_eq!(
+        parsed(&edited(
+            "[tile town]\nincome = 1\n",
+            "[tile town]\nincome = lots\n"
+        )),
+        Err(RulesetError::InvalidValue {
+            line: town + 1,
+            key: "income",
+        })
+    );
+}
@@ -0,0 +1,68 @@
+pub mod common;
+
+use common::{DUEL, builder, declare_battle, play_opening};
+use convoy::{Game, history::UndoPolicy, save::LoadError};
+
+/// Plays a recruitment and a turn each, then has player 1 move in and declare a battle that
+/// awaits the response of player 2
+fn played_game() -> Game {
+    let mut game = builder(DUEL)
+        .undo_policy(UndoPolicy::COMPETITIVE)
+        .build()
+        .expect("pieces are on tiles");
+
+    play_opening(&mut game);
+    declare_battle(&mut game);
+    game
+}
+
+fn saved(game: &Game) -> String {
+    let mut save = vec![];
+
+    game.save(&mut save).expect("saving to memory succeeds");
+    String::from_utf8(save).expect("saves are text")
+}
+
+#[test]
+fn saved_games_load_unchanged() {
+    let game = played_game();
+    let mut loaded = Game::load(saved(&game).as_bytes()).expect("the save is valid");
+
+    assert!(game.pending_battle().is_some());
+    assert_eq!(game.log().len(), 4);
+    assert_eq!(loaded, game);
+    assert_eq!(saved(&loaded), saved(&game));
+
+    let mut game = game;
+
+    assert_eq!(loaded.do_defend(true, &[]), game.do_defend(true, &[]));
+    assert_eq!(loaded, game);
+}
+
+#[test]
+fn saves_outside_the_command_phase_are_rejected() {
+    let save = saved(&played_game());
+
+    for phase in ["income", "resupply"] {
+        let save = save.replace("phase command", &format!("phase {phase}"));
+
+        assert!(matches!(
+            Game::load(save.as_bytes()),
+            Err(LoadError::Malformed { line: 3 })
+        ));
+    }
+}
+
+#[test]
+fn oversized_log_counts_are_rejected() {
+    let save = saved(&played_game());
+
+    assert!(save.contains("\nlog 4\n"));
+
+    let save = save.replace("log 4", &format!("log {}", usize::MAX));
+
+    assert!(matches!(
+        Game::load(save.as_bytes()),
+        Err(LoadError::UnexpectedEnd)
+    ));
+}
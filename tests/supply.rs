@@ -0,0 +1,126 @@
+pub mod common;
+
+use common::{game, piece};
+use convoy::{
+    Game,
+    coordinates::{Coordinate, PieceCoord},
+    player::Player,
+};
+
+/// A player 1 border feeding a chain of two convoys, with infantry at the end of the chain
+/// and just past it
+const CHAIN: &str = "\
+1...........
+...........2
+
+...C..C..I.I
+............
+";
+
+/// The same border with a convoy too far from it to be supplied, and so too far to supply
+/// the infantry beside it
+const BROKEN_CHAIN: &str = "\
+1...........
+...........2
+
+.....C.I....
+............
+";
+
+/// Player 1 infantry beside its border, and player 1 infantry and recon and player 2
+/// infantry far from any border of theirs
+const STRANDED: &str = "\
+1.........
+.........2
+
+.I....i.IR
+..........
+";
+
+/// A road leading from the player 1 border to infantry far from it, with infantry beside the
+/// far end of the road
+const ROAD: &str = "\
+1=========
+.........2
+
+.........I
+........I.
+";
+
+fn is_supplied(game: &Game, rank: u8, file: u8) -> bool {
+    game.supplied_tiles(Player::P1)
+        .iter()
+        .any(|tile| (tile.rank(), tile.file()) == (rank, file))
+}
+
+#[test]
+fn borders_supply_tiles_within_the_supply_radius() {
+    let game = game(BROKEN_CHAIN);
+
+    assert!(is_supplied(&game, 0, 3));
+    assert!(is_supplied(&game, 1, 2));
+    assert!(!is_supplied(&game, 0, 4));
+    assert!(!is_supplied(&game, 1, 3));
+}
+
+#[test]
+fn supplied_convoys_chain_supplies_outwards() {
+    let game = game(CHAIN);
+
+    assert!(game.is_supplied(piece(&game, 0, 6)));
+    assert!(game.is_supplied(piece(&game, 0, 9)));
+    assert!(is_supplied(&game, 1, 8));
+    assert!(!is_supplied(&game, 0, 10));
+    assert!(!is_supplied(&game, 1, 9));
+    assert!(!game.is_supplied(piece(&game, 0, 11)));
+}
+
+#[test]
+fn unsupplied_convoys_supply_nothing() {
+    let game = game(BROKEN_CHAIN);
+
+    assert!(!game.is_supplied(piece(&game, 0, 5)));
+    assert!(!game.is_supplied(piece(&game, 0, 7)));
+}
+
+#[test]
+fn supply_sources_belong_to_one_player() {
+    let game = game(CHAIN);
+    let supplied_to_p2: Vec<_> = game
+        .supplied_tiles(Player::P2)
+        .iter()
+        .map(|tile| (tile.rank(), tile.file()))
+        .collect();
+
+    assert!(supplied_to_p2.contains(&(0, 11)));
+    assert!(!supplied_to_p2.contains(&(0, 0)));
+    assert!(!supplied_to_p2.contains(&(0, 3)));
+    assert!(!is_supplied(&game, 1, 11));
+}
+
+#[test]
+fn ending_the_turn_disbands_unsupplied_pieces_except_recon() {
+    let mut game = game(STRANDED);
+
+    game.do_end_turn().expect("player 1 can end their turn");
+
+    let has_piece = |rank, file| PieceCoord::new(rank, file, game.board()).is_some();
+
+    assert!(has_piece(0, 1));
+    assert!(!has_piece(0, 8));
+    assert!(has_piece(0, 9));
+    assert!(
+        has_piece(0, 6),
+        "only the pieces of the current player are disbanded"
+    );
+}
+
+#[test]
+fn roads_carry_supply_along_their_length() {
+    let game = game(ROAD);
+
+    assert!(is_supplied(&game, 0, 9));
+    assert!(!is_supplied(&game, 1, 8));
+    assert!(game.is_supplied(piece(&game, 0, 9)));
+    assert!(!game.is_supplied(piece(&game, 1, 8)));
+}
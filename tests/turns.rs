@@ -0,0 +1,81 @@
+pub mod common;
+
+use common::{builder, game, piece, tile};
+use convoy::{Game, phase::Phase, player::Player};
+
+/// Each player's border and infantry, with a town beside player 1's infantry
+const BOARD: &str = "\
+1t...
+....2
+
+I....
+...i.
+";
+
+#[test]
+fn the_first_player_collects_income_when_the_game_starts() {
+    let game = Game::new();
+
+    assert_eq!(game.money(Player::P1), 2);
+    assert_eq!(game.money(Player::P2), 0);
+
+    let game = builder(BOARD)
+        .current_player(Player::P2)
+        .money(Player::P1, 5)
+        .money(Player::P2, 5)
+        .build()
+        .expect("pieces are on tiles");
+
+    assert_eq!(game.money(Player::P1), 5);
+    assert_eq!(game.money(Player::P2), 7);
+}
+
+#[test]
+fn ending_the_turn_readies_the_next_player_and_pays_their_income() {
+    let mut game = game(BOARD);
+    game.do_move(piece(&game, 0, 0), tile(&game, 0, 1))
+        .expect("the infantry can move onto the town");
+    game.do_end_turn().expect("player 1 can end their turn");
+
+    assert_eq!(game.current_player(), Player::P2);
+    assert_eq!(game.turn(), 2);
+    assert_eq!(game.phase(), Phase::Command);
+    assert_eq!(game.money(Player::P2), 2);
+
+    game.do_end_turn().expect("player 2 can end their turn");
+
+    assert_eq!(game.money(Player::P1), 2 + 3);
+    assert!(game.can_do_move(piece(&game, 0, 1), tile(&game, 0, 2)));
+}
+
+#[test]
+fn towns_stay_captured_until_an_enemy_moves_onto_them() {
+    let mut game = game("1t...\n....2\n\nI....\n..i..\n");
+    let town = tile(&game, 0, 1);
+
+    assert_eq!(game.income(Player::P1), 2);
+    game.do_move(piece(&game, 0, 0), town)
+        .expect("the infantry can move onto the town");
+    game.do_end_turn().expect("player 1 can end their turn");
+    game.do_end_turn().expect("player 2 can end their turn");
+    game.do_move(piece(&game, 0, 1), tile(&game, 1, 0))
+        .expect("the infantry can leave the town");
+
+    assert_eq!(game.board()[town].controller(), Some(Player::P1));
+    assert_eq!(game.income(Player::P1), 2 + 1);
+    assert_eq!(game.income(Player::P2), 2);
+
+    game.do_end_turn().expect("player 1 can end their turn");
+    game.do_move(piece(&game, 1, 2), town)
+        .expect("the enemy infantry can move onto the town");
+
+    assert_eq!(game.board()[town].controller(), Some(Player::P2));
+    assert_eq!(game.income(Player::P1), 2);
+    assert_eq!(game.income(Player::P2), 2 + 1);
+
+    let money = game.money(Player::P1);
+
+    game.do_end_turn().expect("player 2 can end their turn");
+
+    assert_eq!(game.money(Player::P1), money + 2);
+}
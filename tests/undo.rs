@@ -0,0 +1,125 @@
+pub mod common;
+
+use common::{DUEL, builder, declare_battle, piece, tile};
+use convoy::{Game, history::UndoPolicy, piece::PieceType, player::Player};
+
+fn game(undo_policy: UndoPolicy) -> Game {
+    builder(DUEL)
+        .undo_policy(undo_policy)
+        .build()
+        .expect("pieces are on tiles")
+}
+
+#[test]
+fn undoing_a_recruitment_refunds_it() {
+    let mut game = game(UndoPolicy::CASUAL);
+    let before = game.clone();
+
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 0, 0))
+        .expect("player 1 can afford infantry");
+
+    let after = game.clone();
+
+    assert_eq!(game.money(Player::P1), 0);
+    assert!(game.undo().is_some());
+    assert_eq!(game.money(Player::P1), 2);
+    assert_eq!(game.board(), before.board());
+    assert!(game.log().is_empty());
+
+    assert!(game.redo().is_some());
+    assert_eq!(game.money(Player::P1), 0);
+    assert_eq!(game.board(), after.board());
+    assert_eq!(game.log(), after.log());
+}
+
+#[test]
+fn undoing_a_move_restores_the_piece_and_its_actions() {
+    let mut game = game(UndoPolicy::CASUAL);
+    let before = game.clone();
+
+    game.do_move(piece(&game, 0, 1), tile(&game, 0, 3))
+        .expect("the infantry can move");
+    game.undo();
+
+    assert_eq!(game.board(), before.board());
+    assert!(game.can_do_move(piece(&game, 0, 1), tile(&game, 0, 3)));
+}
+
+#[test]
+fn undoing_a_battle_restores_every_piece() {
+    let mut game = game(UndoPolicy::CASUAL);
+    let before = game.clone();
+
+    declare_battle(&mut game);
+    game.do_defend(true, &[])
+        .expect("the infantry can defend itself");
+    game.undo();
+
+    assert!(game.pending_battle().is_some());
+
+    game.undo();
+
+    assert_eq!(game.board(), before.board());
+    assert!(game.pending_battle().is_none());
+    assert!(!game.can_undo());
+}
+
+#[test]
+fn battles_clear_the_history_in_competitive_play() {
+    let mut game = game(UndoPolicy::COMPETITIVE);
+
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 0, 0))
+        .expect("player 1 can afford infantry");
+
+    assert!(game.can_undo());
+
+    declare_battle(&mut game);
+
+    assert!(!game.can_undo());
+    assert_eq!(game.undo(), None);
+}
+
+#[test]
+fn ending_the_turn_clears_the_history_in_competitive_play() {
+    let mut game = game(UndoPolicy::COMPETITIVE);
+
+    game.do_move(piece(&game, 0, 1), tile(&game, 0, 3))
+        .expect("the infantry can move");
+    game.do_end_turn().expect("player 1 can end their turn");
+
+    assert!(!game.can_undo());
+}
+
+#[test]
+fn new_commands_discard_undone_ones() {
+    let mut game = game(UndoPolicy::CASUAL);
+
+    game.do_move(piece(&game, 0, 1), tile(&game, 0, 3))
+        .expect("the infantry can move");
+    game.undo();
+
+    assert!(game.can_redo());
+
+    game.do_move(piece(&game, 0, 1), tile(&game, 0, 0))
+        .expect("the infantry can move");
+
+    assert!(!game.can_redo());
+    assert_eq!(game.redo(), None);
+}
+
+#[test]
+fn rejected_commands_leave_the_history_alone() {
+    let mut game = game(UndoPolicy::CASUAL);
+
+    game.do_move(piece(&game, 0, 1), tile(&game, 0, 3))
+        .expect("the infantry can move");
+    game.undo();
+
+    let before = game.clone();
+
+    game.do_recruit(PieceType::ARTILLERY, tile(&game, 0, 0))
+        .expect_err("player 1 cannot afford artillery");
+
+    assert_eq!(game, before);
+    assert!(game.can_redo());
+}
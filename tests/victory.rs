@@ -0,0 +1,225 @@
+pub mod common;
+
+use common::{DUEL, builder, declare_battle, piece, tile};
+use convoy::{
+    Game,
+    coordinates::Coord,
+    error::CommandError,
+    piece::PieceType,
+    player::Player,
+    victory::{DrawReason, GameStatus, Objectives, Tiebreak, TurnLimit, VictoryConditions},
+};
+
+/// Each player's border, with player 1's infantry only
+const UNFIELDED_BOARD: &str = "\
+1....
+....2
+
+.I...
+.....
+";
+
+/// Player 1's infantry beside the only border of player 2, whose infantry is elsewhere
+const BORDER_BOARD: &str = "\
+1.2..
+.....
+
+.I...
+....i
+";
+
+/// Each player's border and infantry, with a town beside player 1's infantry
+const TOWN_BOARD: &str = "\
+1t...
+....2
+
+I....
+...i.
+";
+
+fn game(victory_conditions: VictoryConditions) -> Game {
+    builder(DUEL)
+        .victory_conditions(victory_conditions)
+        .build()
+        .expect("pieces are on tiles")
+}
+
+#[test]
+fn losing_every_fielded_piece_loses_the_game() {
+    let mut game = game(VictoryConditions::new());
+
+    assert_eq!(game.status(), GameStatus::Ongoing);
+
+    declare_battle(&mut game);
+    game.do_defend(false, &[])
+        .expect("the infantry can decline to defend");
+
+    assert_eq!(game.status(), GameStatus::Won(Player::P1));
+}
+
+#[test]
+fn players_who_never_fielded_a_piece_are_not_eliminated() {
+    let mut game = common::game(UNFIELDED_BOARD);
+
+    game.do_end_turn().expect("player 1 can end their turn");
+
+    assert_eq!(game.status(), GameStatus::Ongoing);
+
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 1, 4))
+        .expect("player 2 can afford infantry");
+
+    assert_eq!(game.status(), GameStatus::Ongoing);
+}
+
+#[test]
+fn occupying_every_enemy_border_wins_the_game() {
+    let conditions = VictoryConditions {
+        elimination: false,
+        ..VictoryConditions::new()
+    };
+    let mut game = builder(BORDER_BOARD)
+        .victory_conditions(conditions)
+        .build()
+        .expect("pieces are on tiles");
+
+    assert_eq!(game.status(), GameStatus::Ongoing);
+
+    game.do_move(piece(&game, 0, 1), tile(&game, 0, 2))
+        .expect("the infantry can move onto the border");
+
+    assert_eq!(game.status(), GameStatus::Won(Player::P1));
+}
+
+#[test]
+fn reaching_the_money_target_wins_the_game() {
+    let mut game = game(VictoryConditions {
+        money_target: Some(4),
+        ..VictoryConditions::new()
+    });
+
+    game.do_end_turn().expect("player 1 can end their turn");
+
+    assert_eq!(game.status(), GameStatus::Ongoing);
+
+    game.do_end_turn().expect("player 2 can end their turn");
+
+    assert_eq!(game.money(Player::P1), 4);
+    assert_eq!(game.status(), GameStatus::Won(Player::P1));
+}
+
+#[test]
+fn leaving_an_objective_resets_the_streak() {
+    let mut game = game(VictoryConditions {
+        objectives: Some(Objectives {
+            tiles: vec![Coord::new(0, 2)],
+            turns: 2,
+        }),
+        ..VictoryConditions::new()
+    });
+
+    for (from, to, streak) in [(1, 2, 1), (2, 3, 0), (3, 2, 1)] {
+        game.do_move(piece(&game, 0, from), tile(&game, 0, to))
+            .expect("the infantry can move");
+        game.do_end_turn().expect("player 1 can end their turn");
+
+        assert_eq!(game.objective_turns(Player::P1), streak);
+
+        game.do_end_turn().expect("player 2 can end their turn");
+    }
+
+    assert_eq!(game.status(), GameStatus::Ongoing);
+
+    game.do_end_turn().expect("player 1 can end their turn");
+
+    assert_eq!(game.objective_turns(Player::P1), 2);
+    assert_eq!(game.status(), GameStatus::Won(Player::P1));
+}
+
+/// Has player 1 capture the town and player 2 recruit infantry, then plays until the turn
+/// limit has passed
+fn status_at_turn_limit(tiebreak: Tiebreak) -> GameStatus {
+    let conditions = VictoryConditions {
+        turn_limit: Some(TurnLimit { turns: 2, tiebreak }),
+        ..VictoryConditions::new()
+    };
+    let mut game = builder(TOWN_BOARD)
+        .victory_conditions(conditions)
+        .build()
+        .expect("pieces are on tiles");
+
+    game.do_move(piece(&game, 0, 0), tile(&game, 0, 1))
+        .expect("the infantry can move onto the town");
+    game.do_end_turn().expect("player 1 can end their turn");
+    game.do_recruit(PieceType::INFANTRY, tile(&game, 1, 4))
+        .expect("player 2 can afford infantry");
+
+    assert_eq!(game.status(), GameStatus::Ongoing);
+
+    game.do_end_turn().expect("player 2 can end their turn");
+    game.status()
+}
+
+#[test]
+fn the_tiebreak_decides_the_game_at_the_turn_limit() {
+    assert_eq!(
+        status_at_turn_limit(Tiebreak::None),
+        GameStatus::Draw(DrawReason::TurnLimit)
+    );
+    assert_eq!(
+        status_at_turn_limit(Tiebreak::Income),
+        GameStatus::Won(Player::P1)
+    );
+    assert_eq!(
+        status_at_turn_limit(Tiebreak::Material),
+        GameStatus::Won(Player::P2)
+    );
+}
+
+#[test]
+fn tied_tiebreaks_draw_the_game() {
+    for tiebreak in [Tiebreak::Income, Tiebreak::Material] {
+        let mut game = game(VictoryConditions {
+            turn_limit: Some(TurnLimit { turns: 2, tiebreak }),
+            ..VictoryConditions::new()
+        });
+
+        game.do_end_turn().expect("player 1 can end their turn");
+        game.do_end_turn().expect("player 2 can end their turn");
+
+        assert_eq!(game.status(), GameStatus::Draw(DrawReason::TurnLimit));
+    }
+}
+
+#[test]
+fn winning_together_draws_the_game() {
+    let game = builder(DUEL)
+        .victory_conditions(VictoryConditions {
+            money_target: Some(2),
+            ..VictoryConditions::new()
+        })
+        .money(Player::P2, 2)
+        .build()
+        .expect("pieces are on tiles");
+
+    assert_eq!(game.status(), GameStatus::Draw(DrawReason::Simultaneous));
+}
+
+#[test]
+fn commands_are_rejected_once_the_game_is_over() {
+    let mut game = game(VictoryConditions::new());
+
+    declare_battle(&mut game);
+    game.do_defend(false, &[])
+        .expect("the infantry can decline to defend");
+
+    let over = CommandError::GameOver(GameStatus::Won(Player::P1));
+    let before = game.clone();
+
+    assert_eq!(over.to_string(), "the game is over: player 1 won");
+    assert_eq!(game.do_end_turn(), Err(over.clone()));
+    assert_eq!(
+        game.do_recruit(PieceType::INFANTRY, tile(&game, 0, 0)),
+        Err(over)
+    );
+    assert_eq!(game, before);
+}